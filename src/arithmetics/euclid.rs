@@ -1,38 +1,79 @@
-use crate::{
-    equality::Equals,
-    identities::Zero,
-    structures::{integers::Integer, Ring},
-};
+use crate::structures::EuclideanDomain;
 
-pub fn gcd<R>(a: &Integer<R>, b: &Integer<R>) -> Integer<R>
+/// Returns the greatest common divisor of `a` and `b` with the Euclidean algorithm.
+///
+/// The greatest common divisor is only unique up to a unit, e.g. up to the sign for integers. It is
+/// the last non-zero remainder of the algorithm, or `a` if `b` is zero.
+pub fn gcd<E>(a: &E, b: &E) -> E
 where
-    R: Ring + PartialOrd,
+    E: EuclideanDomain,
 {
-    if b.equals(&Integer::zero(), 0.) {
+    if b.is_zero(0.) {
         return a.clone();
     }
-    gcd(b, &(a.clone() % b.clone()))
+    gcd(b, &a.div_rem(b).1)
 }
 
-pub fn euclidean_division<R>(a: &Integer<R>, b: &Integer<R>) -> (Integer<R>, Integer<R>)
+/// Returns the greatest common divisor of all the `values`, or zero if there are none.
+pub fn gcd_all<E>(values: &[E]) -> E
 where
-    R: Ring + PartialOrd,
+    E: EuclideanDomain,
 {
-    let q = a.value().to_owned() / b.value().to_owned();
-    let r = a.value().to_owned() - q.clone() * b.value().to_owned();
-    (Integer::<R>::new(q), Integer::<R>::new(r))
+    values
+        .iter()
+        .fold(E::zero(), |accumulated, value| gcd(&accumulated, value))
 }
 
-pub fn quotient<R>(a: &Integer<R>, b: &Integer<R>) -> Integer<R>
+/// Returns a least common multiple of `a` and `b`, i.e., `a · b / gcd(a, b)`, or zero if any of
+/// them is zero.
+pub fn lcm<E>(a: &E, b: &E) -> E
 where
-    R: Ring + PartialOrd,
+    E: EuclideanDomain,
+{
+    if a.is_zero(0.) || b.is_zero(0.) {
+        return E::zero();
+    }
+    a.div_rem(&gcd(a, b)).0 * b.clone()
+}
+
+/// Returns the quotient and the remainder of the Euclidean division of `a` by `b`, as defined by
+/// [`EuclideanDomain::div_rem`]. For integers the remainder is always non-negative, so
+/// `euclidean_division(-7, 2)` is `(-4, 1)`.
+pub fn euclidean_division<E>(a: &E, b: &E) -> (E, E)
+where
+    E: EuclideanDomain,
+{
+    a.div_rem(b)
+}
+
+/// Returns the quotient of the Euclidean division of `a` by `b`. See [`euclidean_division`].
+pub fn quotient<E>(a: &E, b: &E) -> E
+where
+    E: EuclideanDomain,
 {
     euclidean_division(a, b).0
 }
 
+/// Returns `(d, x, y)` such that `d = gcd(a, b) = a·x + b·y`, i.e., the greatest common divisor
+/// together with the Bézout coefficients.
+pub fn extended_gcd<E>(a: &E, b: &E) -> (E, E, E)
+where
+    E: EuclideanDomain,
+{
+    if b.is_zero(0.) {
+        return (a.clone(), E::one(), E::zero());
+    }
+    let (q, r) = euclidean_division(a, b);
+    let (d, x, y) = extended_gcd(b, &r);
+    (d, y.clone(), x - q * y)
+}
+
 #[cfg(test)]
 mod test {
-    use crate::structures::integers::Integer;
+    use crate::{
+        structures::{big_integers::BigInt, integers::Integer},
+        traits::Abs,
+    };
 
     #[test]
     fn test_euclid() {
@@ -44,10 +85,75 @@ mod test {
         );
     }
 
+    #[test]
+    fn euclidean_division_should_have_non_negative_remainder() {
+        vec![
+            ((-7, 2), (-4, 1)),
+            ((7, 2), (3, 1)),
+            ((7, -2), (-3, 1)),
+            ((-7, -2), (4, 1)),
+            ((-6, 3), (-2, 0)),
+        ]
+        .into_iter()
+        .for_each(|((a, b), (q, r))| {
+            assert_eq!(
+                super::euclidean_division(&Integer::<i32>::new(a), &Integer::new(b)),
+                (Integer::new(q), Integer::new(r)),
+                "{a} / {b}"
+            );
+        });
+    }
+
     #[test]
     fn test_gcd() {
         let a = Integer::<isize>::new(252);
         let b = Integer::<isize>::new(105);
         assert_eq!(super::gcd(&a, &b), Integer::<isize>::new(21));
     }
+
+    #[test]
+    fn test_extended_gcd() {
+        vec![(240, 46), (46, 240), (17, 5), (-12, 18), (7, 0)]
+            .into_iter()
+            .for_each(|(a, b)| {
+                let (a, b) = (Integer::<i64>::new(a), Integer::<i64>::new(b));
+                let (d, x, y) = super::extended_gcd(&a, &b);
+                assert_eq!(d.abs_value(), super::gcd(&a, &b).abs_value());
+                assert_eq!(a * x + b * y, d);
+            });
+    }
+
+    #[test]
+    fn test_gcd_all_and_lcm() {
+        let values = [12, -18, 30].map(Integer::<i64>::new);
+        assert_eq!(super::gcd_all(&values).abs_value(), Integer::new(6));
+        assert_eq!(super::gcd_all::<Integer<i64>>(&[]), Integer::new(0));
+        assert_eq!(
+            super::lcm(&Integer::<i64>::new(4), &Integer::new(6)),
+            Integer::new(12)
+        );
+        assert_eq!(
+            super::lcm(&Integer::<i64>::new(-4), &Integer::new(0)),
+            Integer::new(0)
+        );
+    }
+
+    #[test]
+    fn euclid_should_be_generic_over_euclidean_domains() {
+        assert_eq!(super::gcd(&252_i32, &-105), 21);
+        assert_eq!(super::euclidean_division(&-7_i8, &2), (-4, 1));
+        let (d, x, y) = super::extended_gcd(&240_i128, &46);
+        assert_eq!((d, 240 * x + 46 * y), (2, 2));
+
+        let a = BigInt::from(-7);
+        let b = BigInt::from(2);
+        assert_eq!(
+            super::euclidean_division(&a, &b),
+            (BigInt::from(-4), BigInt::from(1))
+        );
+        assert_eq!(
+            super::lcm(&BigInt::from(2).pow(70), &BigInt::from(6)),
+            BigInt::from(3) * BigInt::from(2).pow(70)
+        );
+    }
 }
@@ -1,2 +1,4 @@
 
 pub mod euclid;
+pub mod primes;
+pub mod roots;
@@ -0,0 +1,298 @@
+use crate::{
+    arithmetics::euclid,
+    identities::{One, Zero},
+    structures::{integers::Integer, EuclideanDomain, Ring},
+};
+
+/// Number of odd divisors tried by trial division before switching to
+/// [Pollard's rho algorithm](https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm), which only
+/// pays off for the large factors.
+const TRIAL_DIVISIONS: usize = 1 << 10;
+
+/// Bases of the [Miller-Rabin test](https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test),
+/// i.e., the primes up to 37, which make it deterministic below _3.3·10<sup>24</sup>_.
+const WITNESSES: usize = 12;
+
+/// Returns the prime factors of `n`, repeated by their multiplicity, in increasing order. It is
+/// empty for zero and for the units _±1_, and the sign of `n` is ignored.
+///
+/// The small factors are found by trial division, and the rest with Pollard's rho algorithm and
+/// the Miller-Rabin test. The modular products never overflow, as they fall back to doubling and
+/// adding when the product cannot be represented.
+///
+/// For numbers above _3.3·10<sup>24</sup>_, like the ones of a
+/// [`BigInt`](crate::structures::big_integers::BigInt) backend, the Miller-Rabin test is
+/// probabilistic, so a composite factor could be reported as prime, although no such number is
+/// known for these bases.
+///
+/// ## Example
+///
+/// The result for _-360_ will be `[2, 2, 2, 3, 3, 5]`.
+///
+/// ## Complexity
+/// The expected complexity is _O(n<sup>1/4</sup>)_ products, instead of the _O(√n)_ divisions of
+/// the trial division.
+pub fn prime_factors<R>(n: &Integer<R>) -> Vec<Integer<R>>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let mut factors = Vec::new();
+    if n.is_zero(0.) {
+        return factors;
+    }
+    let two = Integer::<R>::one() + Integer::one();
+    // The factors two are removed before taking the absolute value, as the absolute value of the
+    // minimum of a primitive type overflows.
+    let mut n = n.clone();
+    while n.div_rem(&two).1.is_zero(0.) {
+        factors.push(two.clone());
+        n = n.div_rem(&two).0;
+    }
+    if n < Integer::zero() {
+        n = -n;
+    }
+
+    let mut divisor = two.clone() + Integer::one();
+    let mut divisions = 0;
+    while divisions < TRIAL_DIVISIONS && divisor <= n.div_rem(&divisor).0 {
+        let (quotient, remainder) = n.div_rem(&divisor);
+        if remainder.is_zero(0.) {
+            factors.push(divisor.clone());
+            n = quotient;
+            continue;
+        }
+        divisor = divisor + two.clone();
+        divisions += 1;
+    }
+    if divisor > n.div_rem(&divisor).0 {
+        if !n.is_one(0.) {
+            factors.push(n);
+        }
+    } else {
+        split(n, &mut factors);
+    }
+    factors.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    factors
+}
+
+/// Pushes the prime factors of `n`, which has no factors smaller than the ones tried by trial
+/// division, so it is odd and greater than every witness.
+fn split<R>(n: Integer<R>, factors: &mut Vec<Integer<R>>)
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    if n.is_one(0.) {
+        return;
+    }
+    if is_probable_prime(&n) {
+        factors.push(n);
+        return;
+    }
+    let factor = pollard_rho(&n);
+    let cofactor = n.div_rem(&factor).0;
+    split(factor, factors);
+    split(cofactor, factors);
+}
+
+/// Miller-Rabin test of an odd `n` greater than every witness.
+fn is_probable_prime<R>(n: &Integer<R>) -> bool
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let two = Integer::<R>::one() + Integer::one();
+    let predecessor = n.clone() - Integer::one();
+    let mut odd = predecessor.clone();
+    let mut exponent = 0;
+    while odd.div_rem(&two).1.is_zero(0.) {
+        odd = odd.div_rem(&two).0;
+        exponent += 1;
+    }
+    let mut base = Integer::one();
+    let witnesses = std::iter::from_fn(|| {
+        // The next prime, which is found by trial division as the witnesses are small.
+        loop {
+            base = base.clone() + Integer::one();
+            let mut divisor = two.clone();
+            while divisor.clone() * divisor.clone() <= base && !base.div_rem(&divisor).1.is_zero(0.)
+            {
+                divisor = divisor + Integer::one();
+            }
+            if divisor.clone() * divisor <= base {
+                continue;
+            }
+            return Some(base.clone());
+        }
+    });
+    witnesses.take(WITNESSES).all(|witness| {
+        let mut x = pow_mod(&witness, &odd, n);
+        if x.is_one(0.) || x == predecessor {
+            return true;
+        }
+        for _ in 1..exponent {
+            x = mul_mod(&x, &x, n);
+            if x == predecessor {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Returns a non-trivial factor of the odd composite `n`.
+fn pollard_rho<R>(n: &Integer<R>) -> Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let two = Integer::<R>::one() + Integer::one();
+    let mut constant = Integer::one();
+    loop {
+        let step = |x: &Integer<R>| add_mod(&mul_mod(x, x, n), &constant, n);
+        let (mut x, mut y) = (two.clone(), two.clone());
+        let factor = loop {
+            x = step(&x);
+            y = step(&step(&y));
+            let difference = match x > y {
+                true => x.clone() - y.clone(),
+                false => y.clone() - x.clone(),
+            };
+            let factor = euclid::gcd(&difference, n);
+            if !factor.is_one(0.) {
+                break factor;
+            }
+        };
+        // The sequence closed a cycle modulo n, so it is retried with another constant.
+        if factor != *n {
+            return factor;
+        }
+        constant = constant + Integer::one();
+    }
+}
+
+/// Returns _a + b (mod m)_, for _a, b < m_, without overflowing.
+fn add_mod<R>(a: &Integer<R>, b: &Integer<R>, m: &Integer<R>) -> Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let complement = m.clone() - b.clone();
+    match *a >= complement {
+        true => a.clone() - complement,
+        false => a.clone() + b.clone(),
+    }
+}
+
+/// Returns _a · b (mod m)_, for _a, b < m_, by doubling and adding if the product overflows.
+fn mul_mod<R>(a: &Integer<R>, b: &Integer<R>, m: &Integer<R>) -> Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    if let Some(product) = a.checked_mul(b) {
+        return product.div_rem(m).1;
+    }
+    let two = Integer::<R>::one() + Integer::one();
+    let (mut a, mut b) = (a.clone(), b.clone());
+    let mut result = Integer::zero();
+    while !b.is_zero(0.) {
+        let (half, bit) = b.div_rem(&two);
+        if bit.is_one(0.) {
+            result = add_mod(&result, &a, m);
+        }
+        a = add_mod(&a, &a, m);
+        b = half;
+    }
+    result
+}
+
+/// Returns _base<sup>exponent</sup> (mod m)_, by exponentiation by squaring.
+fn pow_mod<R>(base: &Integer<R>, exponent: &Integer<R>, m: &Integer<R>) -> Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let two = Integer::<R>::one() + Integer::one();
+    let (mut base, mut exponent) = (base.div_rem(m).1, exponent.clone());
+    let mut result = Integer::one();
+    while !exponent.is_zero(0.) {
+        let (half, bit) = exponent.div_rem(&two);
+        if bit.is_one(0.) {
+            result = mul_mod(&result, &base, m);
+        }
+        base = mul_mod(&base, &base, m);
+        exponent = half;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::prime_factors;
+    use crate::structures::{big_integers::BigInt, integers::Integer};
+
+    #[test]
+    fn prime_factors_should_multiply_back() {
+        struct TestCase {
+            id: &'static str,
+            n: i64,
+            expected: Vec<i64>,
+        }
+        vec![
+            TestCase {
+                id: "Zero",
+                n: 0,
+                expected: vec![],
+            },
+            TestCase {
+                id: "Unit",
+                n: -1,
+                expected: vec![],
+            },
+            TestCase {
+                id: "Small composite",
+                n: -360,
+                expected: vec![2, 2, 2, 3, 3, 5],
+            },
+            TestCase {
+                id: "Largest prime",
+                n: 9_223_372_036_854_775_783,
+                expected: vec![9_223_372_036_854_775_783],
+            },
+            TestCase {
+                id: "Product of two large primes",
+                n: 2_147_483_647 * 2_147_483_629,
+                expected: vec![2_147_483_629, 2_147_483_647],
+            },
+            TestCase {
+                id: "Square of a large prime",
+                n: 3_037_000_493 * 3_037_000_493,
+                expected: vec![3_037_000_493, 3_037_000_493],
+            },
+            TestCase {
+                id: "Minimum",
+                n: i64::MIN,
+                expected: vec![2; 63],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            pretty_assertions::assert_eq!(
+                prime_factors(&Integer::new(test.n)),
+                test.expected
+                    .into_iter()
+                    .map(Integer::new)
+                    .collect::<Vec<_>>(),
+                "Test case: {} failed",
+                test.id
+            );
+        });
+
+        pretty_assertions::assert_eq!(
+            prime_factors(&Integer::new(i8::MIN + 1)),
+            vec![Integer::new(127)]
+        );
+        pretty_assertions::assert_eq!(
+            prime_factors(&Integer::new(BigInt::from(1_000_000_007_i64 * 998_244_353))),
+            vec![
+                Integer::new(BigInt::from(998_244_353_i64)),
+                Integer::new(BigInt::from(1_000_000_007_i64)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use std::{cmp::Ordering, f32::consts::PI};
+
+use crate::{
+    arithmetics::{euclid, primes::prime_factors},
+    equality::Equals,
+    identities::{One, Zero},
+    num_types::{AsF32, FromF32},
+    structures::{
+        complex::Complex, integers::Integer, polynomials::Polynomial, rationals::Rational,
+        reals::Real, EuclideanDomain, Field, Ring,
+    },
+    traits::Abs,
+};
+
+/// Maximum number of iterations of [`complex_roots`], which is only reached when the `tolerance`
+/// is below the precision of [`f32`].
+const MAX_ITERATIONS: usize = 500;
+
+/// Returns all the complex roots of the polynomial, repeated by their multiplicity, computed with
+/// the [Aberth method](https://en.wikipedia.org/wiki/Aberth_method).
+///
+/// The coefficients can be [`Real`], [`Complex`] or [`Rational`] numbers. The roots are sorted by
+/// their real part and then by their imaginary part, and any part smaller than `tolerance` is
+/// dropped, so the real roots have no imaginary part.
+///
+/// ## Parameters
+/// - `tolerance`: The iterations stop when every correction is smaller than `tolerance`, relative
+///   to the modulus of the root when it is greater than one.
+///
+/// ## Example
+///
+/// If you have the polynomial `+1x^3-1x^2+1x-1`, then the result will be `[-1i, +1i, +1]`.
+///
+/// ## Complexity
+/// Each iteration is _O(n^2)_ and the convergence is cubic for simple roots, but only linear for
+/// multiple roots, which are also less precise.
+pub fn complex_roots<R>(polynomial: &Polynomial<R>, tolerance: f32) -> Vec<Complex>
+where
+    R: Ring + Into<Complex>,
+{
+    let polynomial = Polynomial::<Complex>::new(
+        polynomial
+            .coefficients()
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect(),
+    );
+    let Some(degree) = polynomial.degree() else {
+        return Vec::new();
+    };
+    let derivative = polynomial.derivative();
+
+    // By Cauchy's bound, every root has a modulus of at most 1 + max |a_i / a_n|. The initial
+    // approximations are spread on that circle, rotated so that they are not symmetric with
+    // respect to the real axis, as the roots of real polynomials are.
+    let leading = polynomial.leading_coefficient().modulus().value();
+    let radius = 1.
+        + polynomial.coefficients()[..degree]
+            .iter()
+            .map(|coefficient| coefficient.modulus().value() / leading)
+            .fold(0., f32::max);
+    let mut roots = (0..degree)
+        .map(|k| {
+            let angle = 2. * PI * k as f32 / degree as f32 + 0.4;
+            Complex::new(
+                Real::new(radius * angle.cos()),
+                Real::new(radius * angle.sin()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut converged = true;
+        for k in 0..degree {
+            let value = polynomial.evaluate(&roots[k]);
+            let repulsion = (0..degree)
+                .filter(|&j| j != k)
+                .fold(Complex::zero(), |sum, j| {
+                    sum + (roots[k] - roots[j]).inverse_multiplication()
+                });
+            let denominator = derivative.evaluate(&roots[k]) - value * repulsion;
+            if value.is_zero(f32::MIN_POSITIVE) || denominator.is_zero(f32::MIN_POSITIVE) {
+                continue;
+            }
+            let correction = value / denominator;
+            roots[k] = roots[k] - correction;
+            if correction.modulus().value() > tolerance * roots[k].modulus().value().max(1.) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+
+    let drop_small = |part: Real| match part.value().abs() < tolerance {
+        true => Real::zero(),
+        false => part,
+    };
+    let mut roots = roots
+        .into_iter()
+        .map(|root| Complex::new(drop_small(root.re()), drop_small(root.im())))
+        .collect::<Vec<_>>();
+    roots.sort_by(|a, b| {
+        (a.re().value(), a.im().value())
+            .partial_cmp(&(b.re().value(), b.im().value()))
+            .unwrap_or(Ordering::Equal)
+    });
+    roots
+}
+
+/// Returns the positive divisors of `n`, built from its [`prime_factors`]. The ones that cannot
+/// be represented, like the absolute value of the minimum of a primitive type, are left out.
+fn divisors<R>(n: &Integer<R>) -> Vec<Integer<R>>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let factors = prime_factors(n);
+    let mut divisors = vec![Integer::<R>::one()];
+    let mut start = 0;
+    while start < factors.len() {
+        let factor = &factors[start];
+        let multiplicity = factors[start..]
+            .iter()
+            .take_while(|other| *other == factor)
+            .count();
+        divisors = divisors
+            .into_iter()
+            .flat_map(|divisor| {
+                std::iter::successors(Some(divisor), |power| power.checked_mul(factor))
+                    .take(multiplicity + 1)
+            })
+            .collect();
+        start += multiplicity;
+    }
+    divisors
+}
+
+/// Returns the distinct rational roots of the polynomial, in increasing order, found with the
+/// [rational root theorem](https://en.wikipedia.org/wiki/Rational_root_theorem).
+///
+/// The coefficients are multiplied by the least common multiple of their denominators, so that
+/// they are integers _a<sub>0</sub>, ..., a<sub>n</sub>_. Then every rational root is _0_ or
+/// _±p/q_, where _p_ divides the lowest non-zero coefficient and _q_ divides _a<sub>n</sub>_, and
+/// each candidate is checked exactly.
+///
+/// The candidates beyond the [Cauchy bound](https://en.wikipedia.org/wiki/Geometrical_properties_of_polynomial_roots#Lagrange's_and_Cauchy's_bounds)
+/// _1 + max |a<sub>i</sub>| / |a<sub>n</sub>|_ are discarded without evaluating them, and so are
+/// the ones whose evaluation overflows, see [`Polynomial::checked_evaluate`]. Use a
+/// [`BigInt`](crate::structures::big_integers::BigInt) backend if the roots are so large that
+/// their evaluation overflows.
+///
+/// ## Example
+///
+/// If you have the polynomial `+2x^3+5x^2-3x`, then the result will be `[-3/1, 0/1, 1/2]`.
+///
+/// ## Complexity
+/// The divisors are built from the [`prime_factors`] of the two coefficients, whose expected
+/// complexity is _O(a<sup>1/4</sup>)_, and each of the _d·d'_ candidates is evaluated in _O(n)_,
+/// where _d_ and _d'_ are their numbers of divisors.
+pub fn rational_roots<R>(polynomial: &Polynomial<Rational<R>>) -> Vec<Rational<R>>
+where
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
+{
+    let coefficients = polynomial
+        .coefficients()
+        .iter()
+        .map(|coefficient| coefficient.clone().simplified())
+        .collect::<Vec<_>>();
+    let multiple = coefficients
+        .iter()
+        .fold(Integer::one(), |multiple, coefficient| {
+            euclid::lcm(&multiple, coefficient.denominator())
+        });
+    let integers = coefficients
+        .iter()
+        .map(|coefficient| {
+            coefficient.numerator().clone() * (multiple.clone() / coefficient.denominator().clone())
+        })
+        .collect::<Vec<_>>();
+
+    let mut roots = Vec::new();
+    let Some(lowest) = integers.iter().position(|c| !c.is_zero(0.)) else {
+        return roots;
+    };
+    if lowest > 0 {
+        roots.push(Rational::zero());
+    }
+    let leading = integers
+        .last()
+        .expect("The polynomial is not zero")
+        .abs_value();
+    // Every root satisfies |p/q| <= bound / |a_n|, i.e., |p| |a_n| <= bound q.
+    let maximum = integers
+        .iter()
+        .map(|coefficient| coefficient.abs_value())
+        .fold(Integer::zero(), |maximum, coefficient| {
+            if coefficient > maximum {
+                coefficient
+            } else {
+                maximum
+            }
+        });
+    let bound = maximum.checked_add(&leading);
+    let within_bound = |p: &Integer<R>, q: &Integer<R>| {
+        let scaled_bound = bound.as_ref().and_then(|bound| bound.checked_mul(q));
+        match (p.checked_mul(&leading), scaled_bound) {
+            (Some(scaled_p), Some(scaled_bound)) => scaled_p <= scaled_bound,
+            (None, Some(_)) => false,
+            (_, None) => true,
+        }
+    };
+    let leading_divisors = divisors(&leading);
+    for p in divisors(&integers[lowest]) {
+        for q in leading_divisors.iter().filter(|q| within_bound(&p, q)) {
+            let candidate = Rational::new(p.clone(), q.clone()).simplified();
+            for candidate in [candidate.clone(), -candidate] {
+                if polynomial
+                    .checked_evaluate(&candidate)
+                    .is_some_and(|value| value.is_zero(0.))
+                    && !roots.iter().any(|root| root.equals(&candidate, 0.))
+                {
+                    roots.push(candidate);
+                }
+            }
+        }
+    }
+    roots.sort_by(|a, b| {
+        (a.numerator().clone() * b.denominator().clone())
+            .partial_cmp(&(b.numerator().clone() * a.denominator().clone()))
+            .unwrap_or(Ordering::Equal)
+    });
+    roots
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{complex_roots, rational_roots};
+    use crate::{
+        equality::Equals,
+        matrix::square::SquareMatrix,
+        structures::{complex::Complex, polynomials::Polynomial, rationals::Rational, reals::Real},
+    };
+
+    fn assert_roots(computed: Vec<Complex>, expected: &[(f32, f32)], tolerance: f32, id: &str) {
+        pretty_assertions::assert_eq!(computed.len(), expected.len(), "Test case: {} failed", id);
+        computed.iter().zip(expected).for_each(|(root, expected)| {
+            assert!(
+                root.equals(&Complex::from(*expected), tolerance),
+                "Test case: {} failed, got {}",
+                id,
+                root
+            )
+        });
+    }
+
+    #[test]
+    fn complex_roots_of_real_polynomials() {
+        struct TestCase {
+            id: &'static str,
+            polynomial: &'static str,
+            expected: Vec<(f32, f32)>,
+            tolerance: f32,
+        }
+        vec![
+            TestCase {
+                id: "Imaginary roots",
+                polynomial: "x^2 + 1",
+                expected: vec![(0., -1.), (0., 1.)],
+                tolerance: 1e-5,
+            },
+            TestCase {
+                id: "Simple real roots",
+                polynomial: "x^3 - 6x^2 + 11x - 6",
+                expected: vec![(1., 0.), (2., 0.), (3., 0.)],
+                tolerance: 1e-5,
+            },
+            TestCase {
+                id: "Mixed roots",
+                polynomial: "x^3 - x^2 + x - 1",
+                expected: vec![(0., -1.), (0., 1.), (1., 0.)],
+                tolerance: 1e-5,
+            },
+            TestCase {
+                id: "Double root",
+                polynomial: "x^2 - 2x + 1",
+                expected: vec![(1., 0.), (1., 0.)],
+                tolerance: 1e-3,
+            },
+            TestCase {
+                id: "Constant",
+                polynomial: "5",
+                expected: vec![],
+                tolerance: 1e-5,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let polynomial = Polynomial::<Real>::from_str(test.polynomial).unwrap();
+            assert_roots(
+                complex_roots(&polynomial, test.tolerance),
+                &test.expected,
+                test.tolerance,
+                test.id,
+            )
+        });
+    }
+
+    #[test]
+    fn complex_roots_of_complex_and_rational_polynomials() {
+        // (x - i)(x - 2) = x^2 - (2 + i)x + 2i
+        let polynomial = Polynomial::new(vec![
+            Complex::from((0., 2.)),
+            Complex::from((-2., -1.)),
+            Complex::from((1., 0.)),
+        ]);
+        assert_roots(
+            complex_roots(&polynomial, 1e-5),
+            &[(0., 1.), (2., 0.)],
+            1e-5,
+            "Complex coefficients",
+        );
+
+        let polynomial =
+            Polynomial::<Rational<i64>>::from_str("2x^4 + 5x^3 - x^2 + 5x - 3").unwrap();
+        assert_roots(
+            complex_roots(&polynomial, 1e-5),
+            &[(-3., 0.), (0., -1.), (0., 1.), (0.5, 0.)],
+            1e-5,
+            "Rational coefficients",
+        );
+    }
+
+    #[test]
+    fn roots_of_characteristic_polynomials() {
+        let matrix = SquareMatrix::<Real>::from_str("{{2,1},{1,2}}").unwrap();
+        assert_roots(
+            complex_roots(&matrix.characteristic_polynomial().unwrap(), 1e-5),
+            &[(1., 0.), (3., 0.)],
+            1e-5,
+            "Symmetric matrix",
+        );
+        let matrix = SquareMatrix::<Real>::from_str("{{0,-1},{1,0}}").unwrap();
+        assert_roots(
+            complex_roots(&matrix.characteristic_polynomial().unwrap(), 1e-5),
+            &[(0., -1.), (0., 1.)],
+            1e-5,
+            "Rotation matrix",
+        );
+    }
+
+    #[test]
+    fn rational_roots_should_be_exact() {
+        struct TestCase {
+            id: &'static str,
+            polynomial: &'static str,
+            expected: Vec<&'static str>,
+        }
+        vec![
+            TestCase {
+                id: "Integer and fractional roots",
+                polynomial: "2x^4 + 5x^3 - x^2 + 5x - 3",
+                expected: vec!["-3", "1/2"],
+            },
+            TestCase {
+                id: "Zero and repeated roots",
+                polynomial: "x^4 - 2x^3 + x^2",
+                expected: vec!["0", "1"],
+            },
+            TestCase {
+                id: "Rational coefficients",
+                polynomial: "1/2x^2 - 1/3x - 1/6",
+                expected: vec!["-1/3", "1"],
+            },
+            TestCase {
+                id: "Irrational roots",
+                polynomial: "x^2 - 2",
+                expected: vec![],
+            },
+            TestCase {
+                id: "Candidates that overflow",
+                polynomial: "x^4 - 1000000",
+                expected: vec![],
+            },
+            TestCase {
+                id: "Large root",
+                polynomial: "x^3 - 1000000",
+                expected: vec!["100"],
+            },
+            TestCase {
+                id: "Candidates beyond the Cauchy bound",
+                polynomial: "1000000x^2 - 1",
+                expected: vec!["-1/1000", "1/1000"],
+            },
+            TestCase {
+                id: "Large prime constant",
+                polynomial: "x - 9223372036854775783",
+                expected: vec!["9223372036854775783"],
+            },
+            TestCase {
+                id: "Square of a large prime",
+                polynomial: "x^2 - 4611686014132420609",
+                expected: vec!["-2147483647", "2147483647"],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let polynomial = Polynomial::<Rational<i64>>::from_str(test.polynomial).unwrap();
+            let roots = rational_roots(&polynomial);
+            pretty_assertions::assert_eq!(
+                roots.len(),
+                test.expected.len(),
+                "Test case: {} failed",
+                test.id
+            );
+            roots
+                .iter()
+                .zip(test.expected)
+                .for_each(|(root, expected)| {
+                    assert!(
+                        root.equals(&Rational::from_str(expected).unwrap(), 0.),
+                        "Test case: {} failed, got {}",
+                        test.id,
+                        root
+                    )
+                });
+        });
+    }
+}
@@ -1,3 +1,17 @@
+use crate::num_types::AsF64;
+
+/// Tolerance of the comparisons of [`Equals`], [`Zero`](crate::identities::Zero) and
+/// [`One`](crate::identities::One).
+///
+/// It is implemented for [`f32`] and [`f64`], and the comparisons are made in [`f64`], so a
+/// tolerance like `1e-12` is meaningful for the types with double precision, as
+/// [`Real64`](crate::structures::reals::Real64).
+pub trait Tolerance: AsF64 + Copy {}
+
+impl Tolerance for f32 {}
+
+impl Tolerance for f64 {}
+
 /// Substitute trait that implements the "==" operation with a tolerance.
 ///
 /// This is implemented in order to have equality for real numbers (i.e., `f32` and `f64`), with
@@ -6,13 +20,13 @@ pub trait Equals {
     /// Returns wether `self` and `rhs` are equal, with a given tolerance.
     ///
     /// **Note**: The tolerance will be ignored for primitive `isize`, `i8`, `i16`, `i32`, `i64`, `i128` types.
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool;
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool;
 }
 
 macro_rules! impl_equals_for_primitives {
     ($($t:ty),*) => {
         $(impl Equals for $t {
-            fn equals(&self, rhs: &Self, _: f32) -> bool {
+            fn equals<T: Tolerance>(&self, rhs: &Self, _: T) -> bool {
                 self == rhs
             }
         })*
@@ -22,13 +36,13 @@ macro_rules! impl_equals_for_primitives {
 impl_equals_for_primitives!(isize, i8, i16, i32, i64, i128);
 
 impl Equals for f32 {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
-        (self - rhs).abs() < tolerance
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        ((self - rhs).abs() as f64) < tolerance.as_f64()
     }
 }
 
 impl Equals for f64 {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
-        (self - rhs).abs() < tolerance as f64
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        (self - rhs).abs() < tolerance.as_f64()
     }
 }
@@ -3,12 +3,20 @@ mod result;
 use wasm_bindgen::prelude::*;
 
 use crate::{
-    matrix::{generic::Matrix, AsMatrix},
+    matrix::{
+        generic::Matrix,
+        square::{determinant::DeterminantMethod, SquareMatrix},
+        AsMatrix,
+    },
     matrix_reals,
     structures::reals::Real,
 };
 use std::{fmt::Display, str::FromStr};
 
+/// Tolerance used to decide whether an element is zero when the JS side does not provide one.
+/// The elements are [`Real`]s, in single precision, so a smaller tolerance would be meaningless.
+const TOLERANCE: f32 = 1e-6;
+
 /// Initialization function that automatically gets called when the module is loaded in WASM.
 #[wasm_bindgen(start)]
 pub fn start() -> Result<(), JsValue> {
@@ -113,32 +121,34 @@ impl MatrixReal {
     }
 
     pub fn gaussian_triangulation(&self) -> Result<MatrixReal, JsValue> {
-        // let result = self.inner.gaussian_triangulation()?;
-        // Ok(MatrixReal { inner: result })
-        todo!()
+        let result = self.inner.gaussian_elimination(TOLERANCE)?;
+        Ok(MatrixReal { inner: result })
+    }
+
+    /// Returns every step of the gaussian elimination, rendered as text, so that it can be
+    /// shown to students.
+    pub fn gaussian_triangulation_steps(&self) -> Result<String, JsValue> {
+        let trace = self.inner.gaussian_elimination_traced(TOLERANCE)?;
+        Ok(trace.to_string())
     }
 
     pub fn determinant_using_gauss(&self) -> Result<f32, JsValue> {
-        // let result = self
-        //     .inner
-        //     .determinant_using_gauss()
-        //     .ok_or("Matrix is not square!")?;
-        // Ok(result)
-        todo!()
+        let square = SquareMatrix::try_from(self.inner.clone())?;
+        let result = square.determinant(DeterminantMethod::GaussianElimination, TOLERANCE)?;
+        Ok(result.value())
     }
 
     pub fn determinant_using_lu(&self) -> Result<f32, JsValue> {
-        // let result = self
-        //     .inner
-        //     .determinant_using_lu()
-        //     .ok_or("Matrix is not square!")?;
-        // Ok(result)
-        todo!()
+        let square = SquareMatrix::try_from(self.inner.clone())?;
+        let result = square.lu_decomposition(TOLERANCE)?.determinant();
+        Ok(result.value())
     }
 
     pub fn inverse_gauss_jordan(&self) -> Result<MatrixReal, JsValue> {
-        // let result = self.inner.inverse_gauss_jordan()?;
-        // Ok(MatrixReal { inner: result })
-        todo!()
+        let square = SquareMatrix::try_from(self.inner.clone())?;
+        let result = square.inverse_gauss_jordan(TOLERANCE)?;
+        Ok(MatrixReal {
+            inner: Matrix::from(result),
+        })
     }
 }
@@ -1,13 +1,15 @@
+use crate::equality::Tolerance;
+
 /// Representation of the identity for the [`Add`](std::ops::Add) operation.
 pub trait Zero: Sized + Clone + std::ops::Add {
     fn zero() -> Self;
-    fn is_zero(&self, tolerance: f32) -> bool;
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool;
 }
 
 /// Representation of the identity for the [`Mul`](std::ops::Mul) operation.
 pub trait One: Sized + Clone + std::ops::Mul {
     fn one() -> Self;
-    fn is_one(&self, tolerance: f32) -> bool;
+    fn is_one<T: Tolerance>(&self, tolerance: T) -> bool;
 }
 
 macro_rules! impl_identities_for_primitive {
@@ -17,7 +19,7 @@ macro_rules! impl_identities_for_primitive {
                 0 as $t
             }
 
-            fn is_zero(&self, _: f32) -> bool {
+            fn is_zero<T: Tolerance>(&self, _: T) -> bool {
                 *self == 0 as $t
             }
         }
@@ -27,7 +29,7 @@ macro_rules! impl_identities_for_primitive {
                 1 as $t
             }
 
-            fn is_one(&self, _: f32) -> bool {
+            fn is_one<T: Tolerance>(&self, _: T) -> bool {
                 *self == 1 as $t
             }
         })*
@@ -9,6 +9,15 @@ pub enum MatrixError {
     ParseError(String),
     NonSquareMatrix,
     InvalidDimension(usize),
+    SingularMatrix,
+    InconsistentDeterminant(String),
+    Overflow,
+    NonSymmetricMatrix,
+    NotConverged(usize),
+    NotPositiveDefinite(usize),
+    ZeroPivot(usize),
+    UntraceableMethod(String),
+    NotAField(String),
 }
 
 impl std::fmt::Display for MatrixError {
@@ -29,6 +38,34 @@ impl std::fmt::Display for MatrixError {
             MatrixError::InvalidDimension(dimension) => {
                 write!(f, "The dimension {} is invalid", dimension)
             }
+            MatrixError::SingularMatrix => write!(f, "The matrix is singular"),
+            MatrixError::InconsistentDeterminant(e) => {
+                write!(f, "Determinant methods disagree: {}", e)
+            }
+            MatrixError::Overflow => write!(f, "Arithmetic overflow"),
+            MatrixError::NonSymmetricMatrix => write!(f, "The matrix is not symmetric"),
+            MatrixError::NotConverged(iterations) => {
+                write!(
+                    f,
+                    "The method did not converge after {iterations} iterations"
+                )
+            }
+            MatrixError::NotPositiveDefinite(pivot) => {
+                write!(
+                    f,
+                    "The matrix is not positive definite at the pivot {pivot}"
+                )
+            }
+            MatrixError::ZeroPivot(pivot) => write!(f, "The pivot {pivot} is zero"),
+            MatrixError::UntraceableMethod(method) => {
+                write!(f, "The {method} does not perform row operations to trace")
+            }
+            MatrixError::NotAField(method) => {
+                write!(
+                    f,
+                    "The {method} divides by arbitrary pivots, so it requires a field"
+                )
+            }
         }
     }
 }
@@ -0,0 +1,245 @@
+use crate::{
+    equality::Tolerance,
+    matrix::{AsMatrix, MatrixError},
+    structures::{Field, Ring},
+    traits::Sqrt,
+};
+
+use super::{qr::QrMethod, solve::LinearSystemSolution, Matrix};
+
+/// Solution of a least-squares problem, as returned by [`Matrix::least_squares`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquaresSolution<R: Ring> {
+    solution: Vec<R>,
+    residual_norm: R,
+    rank: usize,
+}
+
+impl<R: Ring> LeastSquaresSolution<R> {
+    /// Returns the vector _x_ that minimizes _||Ax - b||_.
+    pub fn solution(&self) -> &[R] {
+        &self.solution
+    }
+
+    /// Returns the euclidean norm of the residual _Ax - b_, which is zero if the system is
+    /// consistent.
+    pub fn residual_norm(&self) -> &R {
+        &self.residual_norm
+    }
+
+    /// Returns the rank of _A_.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+impl<R: Field + PartialOrd + Sqrt> Matrix<R> {
+    /// Returns the vector _x_ that minimizes the euclidean norm _||Ax - b||_, where _A_ is
+    /// `self`, usually with more rows than columns.
+    ///
+    /// If _A_ has full column rank, the minimizer is unique and it is found from the
+    /// [`QrDecomposition`](super::qr::QrDecomposition) _A = QR_ by back substitution in
+    /// _Rx = Q<sup>T</sup>b_, which is numerically stable.
+    ///
+    /// If _A_ is rank deficient, there are infinitely many minimizers, which are the solutions of
+    /// the normal equations _A<sup>T</sup>Ax = A<sup>T</sup>b_. They are solved with
+    /// [`Matrix::solve`], so the result is the basic solution, where every free variable is zero.
+    /// It is not the minimizer of least norm, and the normal equations square the condition
+    /// number of _A_, so the tolerance might need to be increased.
+    ///
+    /// ## Parameters
+    /// - `b`: The right-hand side, with as many elements as rows of _A_.
+    /// - `tolerance`: The tolerance used to determine if a number is zero, and hence the rank.
+    ///
+    /// ## Example
+    ///
+    /// If you want to fit the line _y = c + mx_ to the points _(0, 1)_, _(1, 2)_ and _(2, 4)_,
+    /// you have the system
+    /// ```txt
+    /// 1   0   c     1
+    /// 1   1 · m  =  2
+    /// 1   2         4
+    /// ```
+    /// and the result will be _c = 5/6_ and _m = 3/2_, with a residual norm of _1/√6_ and rank 2.
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::InvalidNumberOfRows`] if `b` has not as many elements as rows of _A_.
+    /// - [`MatrixError::MatrixError`] if the normal equations turn out inconsistent because of the
+    ///   rounding errors, which means that the tolerance is too small.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(mn^2)_.
+    pub fn least_squares<T: Tolerance>(
+        &self,
+        b: &[R],
+        tolerance: T,
+    ) -> Result<LeastSquaresSolution<R>, MatrixError> {
+        if b.len() != self.rows() {
+            return Err(MatrixError::InvalidNumberOfRows);
+        }
+        let columns = self.columns();
+        let rank = self.rank(tolerance)?;
+        let solution = if rank == columns {
+            let qr = self.qr(QrMethod::Householder, tolerance)?;
+            let (q, r) = (qr.q(), qr.r());
+            let mut x = (0..columns)
+                .map(|j| {
+                    b.iter().enumerate().fold(R::zero(), |sum, (i, bi)| {
+                        sum + q[(i, j)].clone() * bi.clone()
+                    })
+                })
+                .collect::<Vec<R>>();
+            for i in (0..columns).rev() {
+                let value = (i + 1..columns).fold(x[i].clone(), |value, j| {
+                    value - r[(i, j)].clone() * x[j].clone()
+                });
+                x[i] = value / r[(i, i)].clone();
+            }
+            x
+        } else {
+            let transpose = self.transpose();
+            let normal = (transpose.clone() * self.clone())?;
+            let rhs = (0..columns)
+                .map(|j| {
+                    b.iter().enumerate().fold(R::zero(), |sum, (i, bi)| {
+                        sum + transpose[(j, i)].clone() * bi.clone()
+                    })
+                })
+                .collect::<Vec<R>>();
+            let particular = match normal.solve(&rhs, tolerance)? {
+                LinearSystemSolution::Unique(x) => x,
+                LinearSystemSolution::Underdetermined { particular, .. } => particular,
+                LinearSystemSolution::Inconsistent => {
+                    return Err(MatrixError::MatrixError(
+                        "The normal equations are inconsistent within the tolerance".to_string(),
+                    ))
+                }
+            };
+            particular
+                .data
+                .into_iter()
+                .map(|row| row[0].clone())
+                .collect()
+        };
+
+        let residual_norm = self
+            .data
+            .iter()
+            .zip(b)
+            .fold(R::zero(), |sum, (row, bi)| {
+                let residual = row
+                    .iter()
+                    .zip(solution.iter())
+                    .fold(R::zero(), |value, (a, x)| value + a.clone() * x.clone())
+                    - bi.clone();
+                sum + residual.clone() * residual
+            })
+            .sqrt_value();
+        Ok(LeastSquaresSolution {
+            solution,
+            residual_norm,
+            rank,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{generic::Matrix, MatrixError},
+        structures::reals::Real,
+    };
+
+    fn reals(values: &[f32]) -> Vec<Real> {
+        values.iter().map(|value| Real::new(*value)).collect()
+    }
+
+    #[test]
+    fn least_squares_should_minimize_the_residual() {
+        struct TestCase {
+            id: &'static str,
+            matrix: &'static str,
+            b: Vec<Real>,
+            expected: Vec<Real>,
+            residual_norm: f32,
+            rank: usize,
+        }
+        vec![
+            TestCase {
+                id: "Line fitting",
+                matrix: "{{1,0},{1,1},{1,2}}",
+                b: reals(&[1., 2., 4.]),
+                expected: reals(&[5. / 6., 1.5]),
+                residual_norm: 1. / 6_f32.sqrt(),
+                rank: 2,
+            },
+            TestCase {
+                id: "Consistent tall system",
+                matrix: "{{2,1},{1,3},{1,1},{0,1}}",
+                b: reals(&[4., 7., 3., 2.]),
+                expected: reals(&[1., 2.]),
+                residual_norm: 0.,
+                rank: 2,
+            },
+            TestCase {
+                id: "Square system",
+                matrix: "{{2,1,1},{4,-6,0},{-2,7,2}}",
+                b: reals(&[5., -2., 9.]),
+                expected: reals(&[1., 1., 2.]),
+                residual_norm: 0.,
+                rank: 3,
+            },
+            TestCase {
+                id: "Rank deficient",
+                matrix: "{{1,2},{2,4},{3,6}}",
+                b: reals(&[1., 2., 4.]),
+                expected: reals(&[17. / 14., 0.]),
+                residual_norm: (5. / 14_f32).sqrt(),
+                rank: 1,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let matrix = Matrix::<Real>::from_str(test.matrix).unwrap();
+            let result = matrix.least_squares(&test.b, 1e-5).unwrap();
+            pretty_assertions::assert_eq!(
+                result.rank(),
+                test.rank,
+                "Test case: {} failed",
+                test.id
+            );
+            result
+                .solution()
+                .iter()
+                .zip(test.expected.iter())
+                .for_each(|(computed, expected)| {
+                    assert!(
+                        computed.equals(expected, 1e-4),
+                        "Test case: {} failed, got {}",
+                        test.id,
+                        computed
+                    )
+                });
+            assert!(
+                result
+                    .residual_norm()
+                    .equals(&Real::new(test.residual_norm), 1e-4),
+                "Test case: {} failed",
+                test.id
+            );
+        });
+    }
+
+    #[test]
+    fn least_squares_with_wrong_right_hand_side_should_fail() {
+        let matrix = Matrix::<Real>::from_str("{{1,0},{1,1},{1,2}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.least_squares(&reals(&[1., 2.]), 1e-6),
+            Err(MatrixError::InvalidNumberOfRows)
+        );
+    }
+}
@@ -1,5 +1,9 @@
+pub mod least_squares;
 pub mod ops;
 pub mod parser;
+pub mod qr;
+pub mod solve;
+pub mod svd;
 
 use std::ops::{Index, IndexMut};
 
@@ -1,7 +1,7 @@
 use std::ops::{Add, Neg, Sub};
 
 use crate::{
-    equality::Equals,
+    equality::{Equals, Tolerance},
     identities::Zero,
     matrix::{AsMatrix, MatrixError},
     structures::Ring,
@@ -9,8 +9,10 @@ use crate::{
 
 use super::Matrix;
 
+/// Compares element by element. To compare by a norm, relative to the size of the matrices, use
+/// [`AsMatrix::equals_in_norm`].
 impl<R: Ring + PartialOrd> Equals for Matrix<R> {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
         if self.rows() != rhs.rows() || self.columns() != rhs.columns() {
             return false;
         }
@@ -25,6 +27,7 @@ impl<R: Ring + PartialOrd> Equals for Matrix<R> {
     }
 }
 
+/// Returns [`MatrixError::Overflow`] if some sum cannot be represented, see [`Ring::checked_add`].
 impl<R: Ring + PartialOrd> Add for Matrix<R> {
     type Output = Result<Self, super::MatrixError>;
 
@@ -36,7 +39,9 @@ impl<R: Ring + PartialOrd> Add for Matrix<R> {
         for (row, row_elements) in self.data.iter().enumerate() {
             for (column, element) in row_elements.iter().enumerate() {
                 let rhs_element = &rhs[(row, column)];
-                result[(row, column)] = element.clone() + rhs_element.clone();
+                result[(row, column)] = element
+                    .checked_add(rhs_element)
+                    .ok_or(MatrixError::Overflow)?;
             }
         }
         Ok(result)
@@ -48,7 +53,7 @@ impl<R: Ring + PartialOrd> Zero for Matrix<R> {
         Matrix::<R>::with_capacity(0, 0)
     }
 
-    fn is_zero(&self, tolerance: f32) -> bool {
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
         self.data
             .iter()
             .all(|row| row.iter().all(|element| element.is_zero(tolerance)))
@@ -69,6 +74,8 @@ impl<R: Ring + PartialOrd> Neg for Matrix<R> {
     }
 }
 
+/// Returns [`MatrixError::Overflow`] if some difference cannot be represented, see
+/// [`Ring::checked_sub`].
 impl<R: Ring + PartialOrd> Sub for Matrix<R> {
     type Output = Result<Self, MatrixError>;
 
@@ -80,13 +87,20 @@ impl<R: Ring + PartialOrd> Sub for Matrix<R> {
         for (row, row_elements) in self.data.iter().enumerate() {
             for (column, element) in row_elements.iter().enumerate() {
                 let rhs_element = &rhs[(row, column)];
-                result[(row, column)] = element.clone() - rhs_element.clone();
+                result[(row, column)] = element
+                    .checked_sub(rhs_element)
+                    .ok_or(MatrixError::Overflow)?;
             }
         }
         Ok(result)
     }
 }
 
+/// The factors of every product keep their order, _a<sub>ik</sub> · b<sub>kj</sub>_, so it works for
+/// non-commutative rings, like the [`Quaternion`](crate::structures::quaternions::Quaternion)s.
+///
+/// Returns [`MatrixError::Overflow`] if some product or sum cannot be represented, see
+/// [`Ring::checked_mul`].
 impl<R: Ring + PartialOrd> std::ops::Mul for Matrix<R> {
     type Output = Result<Self, super::MatrixError>;
 
@@ -97,23 +111,36 @@ impl<R: Ring + PartialOrd> std::ops::Mul for Matrix<R> {
         let mut result = Matrix::<R>::with_capacity(self.rows(), rhs.columns());
         for row in 0..self.rows() {
             for column in 0..rhs.columns() {
-                let mut sum = R::zero();
-                for i in 0..self.columns() {
-                    sum = sum + self[(row, i)].to_owned() * rhs[(i, column)].to_owned();
-                }
-                result[(row, column)] = sum;
+                result[(row, column)] = dot_product(
+                    self.data[row].iter(),
+                    rhs.data.iter().map(|rhs_row| &rhs_row[column]),
+                )?;
             }
         }
         Ok(result)
     }
 }
 
+/// Returns the sum of the products of the `left` and `right` elements, in this order, checking
+/// every operation.
+pub(crate) fn dot_product<'a, R: Ring + 'a>(
+    left: impl Iterator<Item = &'a R>,
+    right: impl Iterator<Item = &'a R>,
+) -> Result<R, MatrixError> {
+    left.zip(right)
+        .try_fold(R::zero(), |sum, (a, b)| {
+            a.checked_mul(b)
+                .and_then(|product| sum.checked_add(&product))
+        })
+        .ok_or(MatrixError::Overflow)
+}
+
 #[cfg(test)]
 mod test {
 
     use crate::{
         equality::Equals,
-        matrix::generic::Matrix,
+        matrix::{generic::Matrix, MatrixError},
         structures::{complex::Complex, integers::Integer, rationals::Rational, reals::Real},
     };
 
@@ -229,4 +256,37 @@ mod test {
             0.
         ),);
     }
+
+    #[test]
+    fn operations_should_detect_overflow() {
+        let big = Matrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::new(i32::MAX), Integer::new(1)],
+            vec![Integer::new(1), Integer::new(1)],
+        ])
+        .unwrap();
+        let small = Matrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::new(1), Integer::new(2)],
+            vec![Integer::new(3), Integer::new(4)],
+        ])
+        .unwrap();
+        pretty_assertions::assert_eq!(big.clone() + small.clone(), Err(MatrixError::Overflow));
+        pretty_assertions::assert_eq!(big.clone() * small.clone(), Err(MatrixError::Overflow));
+        pretty_assertions::assert_eq!(
+            (-big.clone()).unwrap() - big.clone(),
+            Err(MatrixError::Overflow)
+        );
+        let expected = Matrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::new(i32::MAX - 1), Integer::new(-1)],
+            vec![Integer::new(-2), Integer::new(-3)],
+        ])
+        .unwrap();
+        assert!((big - small).unwrap().equals(&expected, 0.));
+
+        let matrix = Matrix::<Integer<i8>>::try_from(vec![
+            vec![Integer::new(100), Integer::new(100)],
+            vec![Integer::new(100), Integer::new(100)],
+        ])
+        .unwrap();
+        pretty_assertions::assert_eq!(matrix.clone() * matrix, Err(MatrixError::Overflow));
+    }
 }
@@ -0,0 +1,279 @@
+use crate::{
+    equality::Tolerance,
+    matrix::{AsMatrix, MatrixError},
+    structures::{Field, Ring},
+    traits::Sqrt,
+};
+
+use super::Matrix;
+
+/// Method used by [`Matrix::qr`] to compute the [`QrDecomposition`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QrMethod {
+    /// Householder reflections, which are numerically stable.
+    Householder,
+    /// Modified Gram-Schmidt orthogonalization of the columns, which is easier to follow by
+    /// hand but loses orthogonality for ill-conditioned matrices.
+    ModifiedGramSchmidt,
+}
+
+/// Reduced [QR decomposition](https://en.wikipedia.org/wiki/QR_decomposition) of an _m×n_
+/// [`Matrix`], i.e., the factorization _A = QR_ where, with _k = min(m, n)_:
+/// - _Q_ is an _m×k_ matrix with orthonormal columns.
+/// - _R_ is a _k×n_ upper triangular matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrDecomposition<R: Ring> {
+    q: Matrix<R>,
+    r: Matrix<R>,
+}
+
+impl<R: Ring> QrDecomposition<R> {
+    /// Returns the matrix _Q_, with orthonormal columns.
+    pub fn q(&self) -> &Matrix<R> {
+        &self.q
+    }
+
+    /// Returns the upper triangular matrix _R_.
+    pub fn r(&self) -> &Matrix<R> {
+        &self.r
+    }
+}
+
+impl<R: Field + PartialOrd + Sqrt> Matrix<R> {
+    /// Returns the reduced [`QrDecomposition`] of the matrix, computed with the given `method`.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a column is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     3   0
+    /// A = 4   5
+    ///     0   0
+    /// ```
+    /// then, up to the signs of the columns of _Q_ and rows of _R_, the result will be
+    /// ```txt
+    ///     0.6  -0.8
+    /// Q = 0.8   0.6       R = 5   4
+    ///     0     0             0   3
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::SingularMatrix`] if the method is
+    /// [`QrMethod::ModifiedGramSchmidt`] and the columns are linearly dependent, as they cannot
+    /// be normalized. Householder reflections work for any matrix.
+    ///
+    /// ## Complexity
+    /// The complexity of both algorithms is _O(mn^2)_.
+    pub fn qr<T: Tolerance>(
+        &self,
+        method: QrMethod,
+        tolerance: T,
+    ) -> Result<QrDecomposition<R>, MatrixError> {
+        match method {
+            QrMethod::Householder => Ok(self.householder_qr(tolerance)),
+            QrMethod::ModifiedGramSchmidt => self.gram_schmidt_qr(tolerance),
+        }
+    }
+
+    fn householder_qr<T: Tolerance>(&self, tolerance: T) -> QrDecomposition<R> {
+        let (rows, columns) = (self.rows(), self.columns());
+        let k = rows.min(columns);
+        let two = R::one() + R::one();
+        let mut r = self.clone();
+        let mut q = Matrix::<R>::with_capacity(rows, rows);
+        for i in 0..rows {
+            q[(i, i)] = R::one();
+        }
+        for j in 0..k.min(rows.saturating_sub(1)) {
+            // The reflection maps x to alpha·e_1, with the sign of alpha opposite to x_0 to
+            // avoid cancellation, and it is I - 2vv^T with v the unit vector along x - alpha·e_1.
+            let below = (j + 1..rows).fold(R::zero(), |sum, i| {
+                sum + r[(i, j)].clone() * r[(i, j)].clone()
+            });
+            if below.sqrt_value().is_zero(tolerance) {
+                continue;
+            }
+            let length = (below.clone() + r[(j, j)].clone() * r[(j, j)].clone()).sqrt_value();
+            let alpha = match r[(j, j)] >= R::zero() {
+                true => -length,
+                false => length,
+            };
+            let mut v = (j..rows).map(|i| r[(i, j)].clone()).collect::<Vec<R>>();
+            v[0] = v[0].clone() - alpha.clone();
+            let v_length = (v[0].clone() * v[0].clone() + below).sqrt_value();
+            let inverse = v_length.inverse_multiplication();
+            v.iter_mut()
+                .for_each(|element| *element = element.clone() * inverse.clone());
+
+            for column in j..columns {
+                let product = v.iter().enumerate().fold(R::zero(), |sum, (i, vi)| {
+                    sum + vi.clone() * r[(j + i, column)].clone()
+                });
+                for (i, vi) in v.iter().enumerate() {
+                    r[(j + i, column)] =
+                        r[(j + i, column)].clone() - two.clone() * vi.clone() * product.clone();
+                }
+            }
+            for row in q.data.iter_mut() {
+                let product = v.iter().enumerate().fold(R::zero(), |sum, (i, vi)| {
+                    sum + row[j + i].clone() * vi.clone()
+                });
+                for (i, vi) in v.iter().enumerate() {
+                    row[j + i] = row[j + i].clone() - two.clone() * product.clone() * vi.clone();
+                }
+            }
+            r[(j, j)] = alpha;
+            for i in j + 1..rows {
+                r[(i, j)] = R::zero();
+            }
+        }
+        QrDecomposition {
+            q: Matrix {
+                data: q.data.into_iter().map(|row| row[..k].to_vec()).collect(),
+            },
+            r: Matrix {
+                data: r.data.into_iter().take(k).collect(),
+            },
+        }
+    }
+
+    fn gram_schmidt_qr<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<QrDecomposition<R>, MatrixError> {
+        let (rows, columns) = (self.rows(), self.columns());
+        let k = rows.min(columns);
+        let mut q = Matrix::<R>::with_capacity(rows, k);
+        let mut r = Matrix::<R>::with_capacity(k, columns);
+        let mut vectors = self.transpose().data;
+        for j in 0..k {
+            let length = vectors[j]
+                .iter()
+                .fold(R::zero(), |sum, x| sum + x.clone() * x.clone())
+                .sqrt_value();
+            if length.is_zero(tolerance) {
+                return Err(MatrixError::SingularMatrix);
+            }
+            let inverse = length.inverse_multiplication();
+            for i in 0..rows {
+                q[(i, j)] = vectors[j][i].clone() * inverse.clone();
+            }
+            r[(j, j)] = length;
+            // Unlike the classical method, the projections are subtracted from the remaining
+            // vectors as soon as each column of Q is known.
+            for (l, vector) in vectors.iter_mut().enumerate().skip(j + 1) {
+                let projection = (0..rows).fold(R::zero(), |sum, i| {
+                    sum + q[(i, j)].clone() * vector[i].clone()
+                });
+                for (i, element) in vector.iter_mut().enumerate() {
+                    *element = element.clone() - projection.clone() * q[(i, j)].clone();
+                }
+                r[(j, l)] = projection;
+            }
+        }
+        Ok(QrDecomposition { q, r })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::QrMethod;
+    use crate::{
+        equality::Equals,
+        matrix::{generic::Matrix, AsMatrix, MatrixError},
+        structures::reals::Real,
+    };
+
+    #[test]
+    fn qr_should_rebuild_the_matrix() {
+        struct TestCase {
+            id: &'static str,
+            matrix: &'static str,
+            method: QrMethod,
+        }
+        vec![
+            TestCase {
+                id: "Householder square",
+                matrix: "{{12,-51,4},{6,167,-68},{-4,24,-41}}",
+                method: QrMethod::Householder,
+            },
+            TestCase {
+                id: "Householder tall",
+                matrix: "{{3,0},{4,5},{0,0},{1,2}}",
+                method: QrMethod::Householder,
+            },
+            TestCase {
+                id: "Householder wide",
+                matrix: "{{1,2,3},{4,5,6}}",
+                method: QrMethod::Householder,
+            },
+            TestCase {
+                id: "Householder rank deficient",
+                matrix: "{{1,2},{2,4},{3,6}}",
+                method: QrMethod::Householder,
+            },
+            TestCase {
+                id: "Gram-Schmidt square",
+                matrix: "{{12,-51,4},{6,167,-68},{-4,24,-41}}",
+                method: QrMethod::ModifiedGramSchmidt,
+            },
+            TestCase {
+                id: "Gram-Schmidt tall",
+                matrix: "{{3,0},{4,5},{0,0},{1,2}}",
+                method: QrMethod::ModifiedGramSchmidt,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let matrix = Matrix::<Real>::from_str(test.matrix).unwrap();
+            let qr = matrix.qr(test.method, 1e-6).unwrap();
+            let k = matrix.rows().min(matrix.columns());
+            pretty_assertions::assert_eq!(
+                (
+                    qr.q().rows(),
+                    qr.q().columns(),
+                    qr.r().rows(),
+                    qr.r().columns()
+                ),
+                (matrix.rows(), k, k, matrix.columns()),
+                "Test case: {} failed",
+                test.id
+            );
+            let product = (qr.q().clone() * qr.r().clone()).unwrap();
+            assert!(
+                product.equals(&matrix, 1e-3),
+                "Test case: {} failed",
+                test.id
+            );
+            let gram = (qr.q().transpose() * qr.q().clone()).unwrap();
+            for i in 0..k {
+                for j in 0..k {
+                    let expected = Real::new(if i == j { 1. } else { 0. });
+                    assert!(
+                        gram[(i, j)].equals(&expected, 1e-5),
+                        "Test case: {} failed",
+                        test.id
+                    );
+                }
+                for j in 0..i {
+                    pretty_assertions::assert_eq!(qr.r()[(i, j)], Real::new(0.));
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn gram_schmidt_should_fail_for_dependent_columns() {
+        let matrix = Matrix::<Real>::from_str("{{1,2},{2,4},{3,6}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.qr(QrMethod::ModifiedGramSchmidt, 1e-6),
+            Err(MatrixError::SingularMatrix)
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{
+    equality::Tolerance,
+    matrix::{AsMatrix, MatrixError},
+    structures::{Field, Ring},
+};
+
+use super::Matrix;
+
+/// Right-hand side of a linear system _Ax = b_. It can be either a vector or a matrix, in
+/// which case every column is a different right-hand side.
+pub trait RightHandSide<R: Ring> {
+    /// Returns the right-hand side as a matrix whose columns are the different right-hand sides.
+    fn as_columns(&self) -> Result<Matrix<R>, MatrixError>;
+}
+
+impl<R: Ring> RightHandSide<R> for [R] {
+    fn as_columns(&self) -> Result<Matrix<R>, MatrixError> {
+        Matrix::try_from(
+            self.iter()
+                .map(|value| vec![value.to_owned()])
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl<R: Ring> RightHandSide<R> for Vec<R> {
+    fn as_columns(&self) -> Result<Matrix<R>, MatrixError> {
+        self.as_slice().as_columns()
+    }
+}
+
+impl<R: Ring> RightHandSide<R> for Matrix<R> {
+    fn as_columns(&self) -> Result<Matrix<R>, MatrixError> {
+        Ok(self.clone())
+    }
+}
+
+/// Classification of the solutions of a linear system _Ax = b_.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LinearSystemSolution<R: Ring> {
+    /// The system has a unique solution. Each column is the solution of the corresponding
+    /// column of the right-hand side.
+    Unique(Matrix<R>),
+    /// The system has infinitely many solutions, which are the `particular` solution plus any
+    /// linear combination of the vectors of the `null_space` basis of _A_.
+    Underdetermined {
+        particular: Matrix<R>,
+        null_space: Vec<Vec<R>>,
+    },
+    /// The system has no solution.
+    Inconsistent,
+}
+
+impl<R: Field + PartialOrd> Matrix<R> {
+    /// Solves the linear system _Ax = b_, where _A_ is `self` and `b` is either a vector or a
+    /// matrix with as many rows as _A_.
+    ///
+    /// The augmented matrix _[A | b]_ is reduced with
+    /// [`gaussian_elimination`](AsMatrix::gaussian_elimination) and the solutions are found by
+    /// back substitution, setting every free variable to zero for the particular solution.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a number is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the system
+    /// ```txt
+    ///  x + 2y =  3
+    /// 2x + 4y =  6
+    /// ```
+    /// then the result will be [`LinearSystemSolution::Underdetermined`] with particular
+    /// solution `(3/2, 0)` and null space basis `{(-2, 1)}`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::InvalidNumberOfRows`] if `b` has not the same number of rows as
+    /// the matrix.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn solve<B, T: Tolerance>(
+        &self,
+        b: &B,
+        tolerance: T,
+    ) -> Result<LinearSystemSolution<R>, MatrixError>
+    where
+        B: RightHandSide<R> + ?Sized,
+    {
+        let b = b.as_columns()?;
+        if b.rows() != self.rows() {
+            return Err(MatrixError::InvalidNumberOfRows);
+        }
+        let unknowns = self.columns();
+        let right_hand_sides = b.columns();
+
+        let augmented = Matrix::try_from(
+            self.data
+                .iter()
+                .zip(b.data.iter())
+                .map(|(row, b_row)| row.iter().chain(b_row.iter()).cloned().collect())
+                .collect::<Vec<Vec<R>>>(),
+        )?;
+        let reduced = augmented.gaussian_elimination(tolerance)?;
+
+        let mut pivots = Vec::new();
+        for row in reduced.data.iter() {
+            match (0..unknowns).find(|column| !row[*column].is_zero(tolerance)) {
+                Some(column) => pivots.push(column),
+                None => {
+                    if row[unknowns..]
+                        .iter()
+                        .any(|element| !element.is_zero(tolerance))
+                    {
+                        return Ok(LinearSystemSolution::Inconsistent);
+                    }
+                }
+            }
+        }
+
+        let mut solution = Matrix::<R>::with_capacity(unknowns, right_hand_sides);
+        for k in 0..right_hand_sides {
+            let mut x = vec![R::zero(); unknowns];
+            back_substitution(&reduced, &pivots, &mut x, |row| {
+                reduced[(row, unknowns + k)].to_owned()
+            });
+            for (row, value) in x.into_iter().enumerate() {
+                solution[(row, k)] = value;
+            }
+        }
+
+        if pivots.len() == unknowns {
+            return Ok(LinearSystemSolution::Unique(solution));
+        }
+
+        let null_space = (0..unknowns)
+            .filter(|column| !pivots.contains(column))
+            .map(|free| {
+                let mut v = vec![R::zero(); unknowns];
+                v[free] = R::one();
+                back_substitution(&reduced, &pivots, &mut v, |_| R::zero());
+                v
+            })
+            .collect();
+
+        Ok(LinearSystemSolution::Underdetermined {
+            particular: solution,
+            null_space,
+        })
+    }
+}
+
+/// Fills the pivot variables of `x` from the row echelon form `reduced`, where `pivots[i]` is
+/// the pivot column of row `i` and `rhs(i)` is the right-hand side of row `i`. The free
+/// variables must already be set in `x`.
+fn back_substitution<R, F>(reduced: &Matrix<R>, pivots: &[usize], x: &mut [R], rhs: F)
+where
+    R: Field + PartialOrd,
+    F: Fn(usize) -> R,
+{
+    for (row, pivot) in pivots.iter().enumerate().rev() {
+        let mut value = rhs(row);
+        for (column, x_column) in x.iter().enumerate().skip(pivot + 1) {
+            value = value - reduced[(row, column)].to_owned() * x_column.to_owned();
+        }
+        x[*pivot] = value / reduced[(row, *pivot)].to_owned();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        identities::Zero,
+        matrix::{
+            generic::{solve::LinearSystemSolution, Matrix},
+            MatrixError,
+        },
+        structures::{rationals::Rational, reals::Real},
+    };
+
+    #[test]
+    fn solve_system_with_unique_solution() {
+        let a = Matrix::<Rational<i64>>::from_str("{{2,1,1},{4,-6,0},{-2,7,2}}").unwrap();
+        let b = vec![Rational::from(5), Rational::from(-2), Rational::from(9)];
+        let expected = Matrix::<Rational<i64>>::from_str("{{1},{1},{2}}").unwrap();
+        match a.solve(&b, 0.).unwrap() {
+            LinearSystemSolution::Unique(x) => assert!(x.equals(&expected, 0.)),
+            other => panic!("Expected a unique solution but got {:?}", other),
+        }
+
+        let a = Matrix::<Real>::from_str("{{0,1},{1,1},{2,2}}").unwrap();
+        let b = Matrix::<Real>::from_str("{{1,2},{3,3},{6,6}}").unwrap();
+        let expected = Matrix::<Real>::from_str("{{2,1},{1,2}}").unwrap();
+        match a.solve(&b, 1e-6).unwrap() {
+            LinearSystemSolution::Unique(x) => assert!(x.equals(&expected, 1e-5)),
+            other => panic!("Expected a unique solution but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn solve_underdetermined_system() {
+        let a = Matrix::<Rational<i64>>::from_str("{{1,2,1,0},{2,4,0,2},{3,6,1,2}}").unwrap();
+        let b = vec![Rational::from(1), Rational::from(2), Rational::from(3)];
+        let LinearSystemSolution::Underdetermined {
+            particular,
+            null_space,
+        } = a.solve(&b, 0.).unwrap()
+        else {
+            panic!("Expected an underdetermined system");
+        };
+        pretty_assertions::assert_eq!(null_space.len(), 2);
+        let expected_b = Matrix::<Rational<i64>>::from_str("{{1},{2},{3}}").unwrap();
+        assert!((a.clone() * particular).unwrap().equals(&expected_b, 0.));
+        for v in null_space {
+            let v = Matrix::try_from(v.into_iter().map(|x| vec![x]).collect::<Vec<_>>()).unwrap();
+            assert!((a.clone() * v).unwrap().is_zero(0.));
+        }
+    }
+
+    #[test]
+    fn solve_inconsistent_system() {
+        let a = Matrix::<Real>::from_str("{{1,2},{2,4}}").unwrap();
+        let b = vec![Real::new(1.), Real::new(3.)];
+        pretty_assertions::assert_eq!(
+            a.solve(&b, 1e-6).unwrap(),
+            LinearSystemSolution::Inconsistent
+        );
+    }
+
+    #[test]
+    fn solve_with_wrong_right_hand_side_should_fail() {
+        let a = Matrix::<Real>::from_str("{{1,2},{2,4}}").unwrap();
+        let b = vec![Real::new(1.)];
+        pretty_assertions::assert_eq!(a.solve(&b, 1e-6), Err(MatrixError::InvalidNumberOfRows));
+    }
+}
@@ -4,10 +4,21 @@ use std::{fmt::Display, ops::IndexMut, str::FromStr};
 
 pub use error::MatrixError;
 
-use crate::structures::Ring;
+use crate::{
+    equality::Tolerance,
+    structures::{complex::Complex64, Field, IntegralDomain, Ring},
+};
+
+use self::{
+    norms::MatrixNorm,
+    square::determinant::Signature,
+    trace::{EliminationTrace, RowOperation},
+};
 
 pub mod generic;
+pub mod norms;
 pub mod square;
+pub mod trace;
 
 pub trait AsMatrix<R>:
     TryFrom<Vec<Vec<R>>> + Default + FromStr + Display + Clone + IndexMut<(usize, usize), Output = R>
@@ -58,10 +69,40 @@ where
     ///     0   0   0
     /// ```
     ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::NotAField`] if `R` is not a [`Field`], see
+    /// [`IntegralDomain::IS_FIELD`], as its exact division cannot eliminate below any pivot.
+    ///
     /// ## Complexity
     /// The complexity of this algorithm is _O(n^3)_.
-    fn gaussian_elimination(&self, tolerance: f32) -> Result<Self, MatrixError> {
+    fn gaussian_elimination<T: Tolerance>(&self, tolerance: T) -> Result<Self, MatrixError>
+    where
+        R: IntegralDomain,
+    {
+        Ok(self.gaussian_elimination_with_signature(tolerance)?.0)
+    }
+
+    /// Performs the same gaussian elimination as [`AsMatrix::gaussian_elimination`], but also
+    /// returns the [`Signature`] of the permutation of rows performed by the pivoting.
+    ///
+    /// This is needed to compute determinants, as every row swap changes their sign.
+    ///
+    /// ## Errors
+    ///
+    /// The errors of [`AsMatrix::gaussian_elimination`].
+    fn gaussian_elimination_with_signature<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<(Self, Signature), MatrixError>
+    where
+        R: IntegralDomain,
+    {
+        if !R::IS_FIELD {
+            return Err(MatrixError::NotAField("Gaussian elimination".to_string()));
+        }
         let mut matrix = self.clone();
+        let mut sign = Signature::Even;
         let mut i = 0;
         let mut j = 0;
         while i < matrix.rows() && j < matrix.columns() {
@@ -74,7 +115,10 @@ where
             if matrix.data()[max_row][j].is_zero(tolerance) {
                 j += 1;
             } else {
-                matrix.swap_rows(i, max_row)?;
+                if max_row != i {
+                    matrix.swap_rows(i, max_row)?;
+                    sign.change();
+                }
                 for k in i + 1..matrix.rows() {
                     let factor = matrix.data()[k][j].clone() / matrix.data()[i][j].clone();
                     matrix.data_mut()[k][j] = R::zero();
@@ -88,7 +132,301 @@ where
                 j += 1;
             }
         }
-        Ok(matrix)
+        Ok((matrix, sign))
+    }
+
+    /// Performs the same gaussian elimination as [`AsMatrix::gaussian_elimination`], but
+    /// records every elementary row operation and every intermediate matrix in an
+    /// [`EliminationTrace`].
+    ///
+    /// Each elimination is recorded as adding a multiple of the pivot row, so zeros might be
+    /// approximated within `tolerance` for inexact types.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a number is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     1   2
+    /// M = 3   4
+    /// ```
+    /// then the trace will contain the steps
+    /// ```txt
+    /// R1 <-> R2
+    /// R2 -> R2 + (-1/3) * R1
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// The errors of [`AsMatrix::gaussian_elimination`].
+    fn gaussian_elimination_traced<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<EliminationTrace<R, Self>, MatrixError>
+    where
+        R: IntegralDomain,
+    {
+        if !R::IS_FIELD {
+            return Err(MatrixError::NotAField("Gaussian elimination".to_string()));
+        }
+        let mut trace = EliminationTrace::new(self.clone());
+        let mut i = 0;
+        let mut j = 0;
+        while i < self.rows() && j < self.columns() {
+            let matrix = trace.result();
+            let mut max_row = i;
+            for k in i + 1..matrix.rows() {
+                if matrix.data()[k][j].abs_value() > matrix.data()[max_row][j].abs_value() {
+                    max_row = k;
+                }
+            }
+            if matrix.data()[max_row][j].is_zero(tolerance) {
+                j += 1;
+                continue;
+            }
+            if max_row != i {
+                trace.push(RowOperation::Swap {
+                    first: i,
+                    second: max_row,
+                })?;
+            }
+            for k in i + 1..self.rows() {
+                let matrix = trace.result();
+                if matrix.data()[k][j].is_zero(tolerance) {
+                    continue;
+                }
+                let factor = matrix.data()[k][j].clone() / matrix.data()[i][j].clone();
+                trace.push(RowOperation::AddMultiple {
+                    factor: -factor,
+                    source: i,
+                    target: k,
+                })?;
+            }
+            i += 1;
+            j += 1;
+        }
+        Ok(trace)
+    }
+
+    /// Returns a brand new matrix in reduced row echelon form, together with the indexes of
+    /// the pivot columns.
+    ///
+    /// It starts from the result of [`AsMatrix::gaussian_elimination`], scales every pivot to
+    /// one and eliminates the elements above it.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a number is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     1   2   3
+    /// M = 4   5   6
+    ///     7   8   9
+    /// ```
+    /// then the result will be the matrix
+    /// ```txt
+    ///     1   0  -1
+    /// M'= 0   1   2
+    ///     0   0   0
+    /// ```
+    /// and the pivot columns `[0, 1]`.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    fn reduced_row_echelon_form<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<(Self, Vec<usize>), MatrixError>
+    where
+        R: Field,
+    {
+        let mut matrix = self.gaussian_elimination(tolerance)?;
+        let mut pivots = Vec::new();
+        for row in matrix.row_iter() {
+            match row.iter().position(|element| !element.is_zero(tolerance)) {
+                Some(column) => pivots.push(column),
+                None => break,
+            }
+        }
+        for (i, &pivot) in pivots.iter().enumerate().rev() {
+            let pivot_inverse = matrix.data()[i][pivot].inverse_multiplication();
+            for l in pivot + 1..matrix.columns() {
+                matrix.data_mut()[i][l] = matrix.data()[i][l].clone() * pivot_inverse.clone();
+            }
+            matrix.data_mut()[i][pivot] = R::one();
+            for k in 0..i {
+                let factor = matrix.data()[k][pivot].clone();
+                if factor.is_zero(tolerance) {
+                    continue;
+                }
+                matrix.data_mut()[k][pivot] = R::zero();
+                for l in pivot + 1..matrix.columns() {
+                    let new_value =
+                        matrix.data()[k][l].clone() - matrix.data()[i][l].clone() * factor.clone();
+                    matrix.data_mut()[k][l] = new_value;
+                }
+            }
+        }
+        Ok((matrix, pivots))
+    }
+
+    /// Returns the rank of the matrix, i.e., the number of pivots of its row echelon form.
+    fn rank<T: Tolerance>(&self, tolerance: T) -> Result<usize, MatrixError>
+    where
+        R: Field,
+    {
+        Ok(self.reduced_row_echelon_form(tolerance)?.1.len())
+    }
+
+    /// Returns the nullity of the matrix, i.e., the dimension of its null space. By the
+    /// rank-nullity theorem, it is the number of columns minus the rank.
+    fn nullity<T: Tolerance>(&self, tolerance: T) -> Result<usize, MatrixError>
+    where
+        R: Field,
+    {
+        Ok(self.columns() - self.rank(tolerance)?)
+    }
+
+    /// Returns a basis of the column space of the matrix, made of the columns of the original
+    /// matrix that are pivot columns in its reduced row echelon form.
+    fn column_space_basis<T: Tolerance>(&self, tolerance: T) -> Result<Vec<Vec<R>>, MatrixError>
+    where
+        R: Field,
+    {
+        let (_, pivots) = self.reduced_row_echelon_form(tolerance)?;
+        Ok(pivots
+            .into_iter()
+            .map(|column| self.row_iter().map(|row| row[column].clone()).collect())
+            .collect())
+    }
+
+    /// Returns a basis of the row space of the matrix, made of the non-zero rows of its reduced
+    /// row echelon form.
+    fn row_space_basis<T: Tolerance>(&self, tolerance: T) -> Result<Vec<Vec<R>>, MatrixError>
+    where
+        R: Field,
+    {
+        let (reduced, pivots) = self.reduced_row_echelon_form(tolerance)?;
+        Ok(reduced.row_iter().take(pivots.len()).cloned().collect())
+    }
+
+    /// Returns a basis of the null space of the matrix, i.e., of the solutions of _Mx = 0_.
+    ///
+    /// There is one vector for each free (non-pivot) column of the reduced row echelon form,
+    /// with a one in that free variable and zero in the others.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     1   2   3
+    /// M = 4   5   6
+    ///     7   8   9
+    /// ```
+    /// then the result will be `[[1, -2, 1]]`.
+    fn null_space_basis<T: Tolerance>(&self, tolerance: T) -> Result<Vec<Vec<R>>, MatrixError>
+    where
+        R: Field,
+    {
+        let (reduced, pivots) = self.reduced_row_echelon_form(tolerance)?;
+        Ok((0..self.columns())
+            .filter(|column| !pivots.contains(column))
+            .map(|free| {
+                let mut v = vec![R::zero(); self.columns()];
+                v[free] = R::one();
+                for (i, &pivot) in pivots.iter().enumerate() {
+                    v[pivot] = -reduced.data()[i][free].clone();
+                }
+                v
+            })
+            .collect())
+    }
+
+    /// Returns the given [`MatrixNorm`] of the matrix.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    /// M = 1  -2
+    ///     3   4
+    /// ```
+    /// then the Frobenius norm will be _√30_, the 1-norm `6`, the ∞-norm `7`, the max-abs norm
+    /// `4` and the spectral norm _√(15 + 5√5)_.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::NotConverged`] if the singular values of the spectral norm do not
+    /// converge.
+    fn norm(&self, norm: MatrixNorm) -> Result<f64, MatrixError>
+    where
+        R: Into<Complex64>,
+    {
+        let data = self
+            .row_iter()
+            .map(|row| row.iter().map(|x| x.clone().into()).collect())
+            .collect::<Vec<Vec<Complex64>>>();
+        norm.compute(&data)
+    }
+
+    /// Returns the distance to the `other` matrix, i.e., the given [`MatrixNorm`] of the
+    /// difference.
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::InvalidNumberOfRows`] or [`MatrixError::InvalidNumberOfColumns`] if the
+    ///   dimensions do not match.
+    /// - The errors of [`AsMatrix::norm`].
+    fn distance(&self, other: &Self, norm: MatrixNorm) -> Result<f64, MatrixError>
+    where
+        R: Into<Complex64>,
+    {
+        if self.rows() != other.rows() {
+            return Err(MatrixError::InvalidNumberOfRows);
+        }
+        if self.columns() != other.columns() {
+            return Err(MatrixError::InvalidNumberOfColumns);
+        }
+        let data = self
+            .row_iter()
+            .zip(other.row_iter())
+            .map(|(row, other_row)| {
+                row.iter()
+                    .zip(other_row)
+                    .map(|(x, y)| (x.clone() - y.clone()).into())
+                    .collect()
+            })
+            .collect::<Vec<Vec<Complex64>>>();
+        norm.compute(&data)
+    }
+
+    /// Checks if the matrices are equal in the given [`MatrixNorm`], relative to their size:
+    /// _||A - B|| < tolerance · max(||A||, ||B||, 1)_.
+    ///
+    /// Unlike [`Equals`](crate::equality::Equals), which compares element by element, it admits
+    /// the rounding errors accumulated by the large matrices. Matrices with different dimensions
+    /// are never equal.
+    ///
+    /// For the [`MatrixNorm::Spectral`], the sizes _||A||_ and _||B||_ are bounded from above by
+    /// their Frobenius norms, so that only the singular values of _A - B_ are computed.
+    fn equals_in_norm<T: Tolerance>(&self, other: &Self, norm: MatrixNorm, tolerance: T) -> bool
+    where
+        R: Into<Complex64>,
+    {
+        let as_complex = |data: &[Vec<R>]| {
+            data.iter()
+                .map(|row| row.iter().cloned().map(Into::into).collect())
+                .collect::<Vec<Vec<Complex64>>>()
+        };
+        norm.equals(
+            &as_complex(self.data()),
+            &as_complex(other.data()),
+            tolerance.as_f64(),
+        )
     }
 
     /// Returns a brand new matrix that is equal to the original matrix, but with the column
@@ -138,8 +476,17 @@ mod tests {
 
     use crate::{
         equality::Equals,
-        matrix::{error::MatrixError, generic::Matrix, AsMatrix},
-        structures::{integers::Integer, rationals::Rational, reals::Real, Ring},
+        identities::Zero,
+        matrix::{
+            error::MatrixError, generic::Matrix, norms::MatrixNorm, square::SquareMatrix, AsMatrix,
+        },
+        structures::{
+            complex::Complex,
+            integers::Integer,
+            rationals::Rational,
+            reals::{Real, Real64},
+            Field, IntegralDomain,
+        },
     };
 
     #[test]
@@ -255,7 +602,7 @@ mod tests {
 
     const TOLERANCE: f32 = 1e-12;
 
-    fn perform_test<R: Ring + PartialOrd>(
+    fn perform_test<R: IntegralDomain + PartialOrd>(
         test: TestCase<'_>,
         builder: fn(&str) -> Result<Matrix<R>, MatrixError>,
     ) {
@@ -326,4 +673,220 @@ mod tests {
         .into_iter()
         .for_each(|test| perform_test(test, Matrix::<Rational<i32>>::from_str))
     }
+
+    fn perform_rref_test<R: Field + PartialOrd>(
+        test: TestCase<'_>,
+        expected_pivots: Vec<usize>,
+        builder: fn(&str) -> Result<Matrix<R>, MatrixError>,
+        tolerance: f32,
+    ) {
+        let matrix = builder(test.matrix).unwrap();
+        let expected = builder(test.expected).unwrap();
+        let (reduced, pivots) = matrix.reduced_row_echelon_form(tolerance).unwrap();
+        assert!(
+            reduced.equals(&expected, 1e-5),
+            "Test case: {} failed. Expected\n{expected}but got\n{reduced}",
+            test.id,
+        );
+        pretty_assertions::assert_eq!(pivots, expected_pivots, "Test case: {}", test.id);
+        pretty_assertions::assert_eq!(matrix.rank(tolerance).unwrap(), pivots.len());
+        pretty_assertions::assert_eq!(
+            matrix.nullity(tolerance).unwrap(),
+            matrix.columns() - pivots.len()
+        );
+    }
+
+    #[test]
+    fn reduced_row_echelon_form_with_rational_matrix() {
+        vec![
+            (
+                TestCase {
+                    id: "Singular 3x3",
+                    matrix: "{{1,2,3},{4,5,6},{7,8,9}}",
+                    expected: "{{1,0,-1},{0,1,2},{0,0,0}}",
+                },
+                vec![0, 1],
+            ),
+            (
+                TestCase {
+                    id: "Invertible 2x2",
+                    matrix: "{{1,2},{3,4}}",
+                    expected: "{{1,0},{0,1}}",
+                },
+                vec![0, 1],
+            ),
+            (
+                TestCase {
+                    id: "Free column in the middle",
+                    matrix: "{{1,2,1,0},{2,4,0,2},{3,6,1,2}}",
+                    expected: "{{1,2,0,1},{0,0,1,-1},{0,0,0,0}}",
+                },
+                vec![0, 2],
+            ),
+            (
+                TestCase {
+                    id: "Zero matrix",
+                    matrix: "{{0,0},{0,0}}",
+                    expected: "{{0,0},{0,0}}",
+                },
+                vec![],
+            ),
+        ]
+        .into_iter()
+        .for_each(|(test, pivots)| {
+            perform_rref_test(test, pivots, Matrix::<Rational<i32>>::from_str, 0.)
+        });
+    }
+
+    #[test]
+    fn reduced_row_echelon_form_with_real_matrix() {
+        vec![
+            (
+                TestCase {
+                    id: "Singular 3x3",
+                    matrix: "{{1,2,3},{4,5,6},{7,8,9}}",
+                    expected: "{{1,0,-1},{0,1,2},{0,0,0}}",
+                },
+                vec![0, 1],
+            ),
+            (
+                TestCase {
+                    id: "More rows than columns",
+                    matrix: "{{1,2},{3,4},{5,6},{7,8}}",
+                    expected: "{{1,0},{0,1},{0,0},{0,0}}",
+                },
+                vec![0, 1],
+            ),
+        ]
+        .into_iter()
+        .for_each(|(test, pivots)| perform_rref_test(test, pivots, Matrix::<Real>::from_str, 1e-5));
+    }
+
+    #[test]
+    fn fundamental_subspaces_with_rational_matrix() {
+        let matrix = Matrix::<Rational<i32>>::from_str("{{1,2,1,0},{2,4,0,2},{3,6,1,2}}").unwrap();
+        let rational = |s: &str| Rational::<i32>::from_str(s).unwrap();
+
+        let column_space = matrix.column_space_basis(0.).unwrap();
+        pretty_assertions::assert_eq!(
+            column_space,
+            vec![
+                vec![rational("1"), rational("2"), rational("3")],
+                vec![rational("1"), rational("0"), rational("1")],
+            ]
+        );
+
+        let row_space = matrix.row_space_basis(0.).unwrap();
+        pretty_assertions::assert_eq!(row_space.len(), 2);
+
+        let null_space = matrix.null_space_basis(0.).unwrap();
+        pretty_assertions::assert_eq!(null_space.len(), 2);
+        for v in null_space {
+            let v = Matrix::try_from(v.into_iter().map(|x| vec![x]).collect::<Vec<_>>()).unwrap();
+            assert!((matrix.clone() * v).unwrap().is_zero(0.));
+        }
+    }
+
+    #[test]
+    fn norms_of_matrices() {
+        struct TestCase {
+            id: &'static str,
+            norm: MatrixNorm,
+            expected: f64,
+        }
+        let real = Matrix::<Real>::from_str("{{1,-2},{3,4}}").unwrap();
+        let integer = Matrix::<Integer<i32>>::from_str("{{1,-2},{3,4}}").unwrap();
+        vec![
+            TestCase {
+                id: "Frobenius",
+                norm: MatrixNorm::Frobenius,
+                expected: 30_f64.sqrt(),
+            },
+            TestCase {
+                id: "One",
+                norm: MatrixNorm::One,
+                expected: 6.,
+            },
+            TestCase {
+                id: "Infinity",
+                norm: MatrixNorm::Infinity,
+                expected: 7.,
+            },
+            TestCase {
+                id: "Max abs",
+                norm: MatrixNorm::MaxAbs,
+                expected: 4.,
+            },
+            TestCase {
+                id: "Spectral",
+                norm: MatrixNorm::Spectral,
+                expected: (15. + 5. * 5_f64.sqrt()).sqrt(),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            for computed in [real.norm(test.norm), integer.norm(test.norm)] {
+                assert!(
+                    computed.unwrap().equals(&test.expected, 1e-4),
+                    "Test case: {} failed",
+                    test.id
+                );
+            }
+        });
+
+        // The spectral norm of a complex matrix is not the one of its real part.
+        let complex = Matrix::try_from(vec![
+            vec![Complex::new(Real::new(0.), Real::new(1.)), Complex::zero()],
+            vec![Complex::zero(), Complex::new(Real::new(3.), Real::new(4.))],
+        ])
+        .unwrap();
+        assert!(complex
+            .norm(MatrixNorm::Spectral)
+            .unwrap()
+            .equals(&5., 1e-4));
+        assert!(complex.norm(MatrixNorm::One).unwrap().equals(&5., 1e-6));
+    }
+
+    #[test]
+    fn distance_and_equality_in_norm() {
+        let matrix = Matrix::<Real>::from_str("{{1000,2000},{3000,4000}}").unwrap();
+        let perturbed = Matrix::<Real>::from_str("{{1000.1,2000},{3000,3999.9}}").unwrap();
+        assert!(matrix
+            .distance(&perturbed, MatrixNorm::MaxAbs)
+            .unwrap()
+            .equals(&0.1, 1e-3));
+        assert!(!matrix.equals(&perturbed, 1e-3));
+        assert!(matrix.equals_in_norm(&perturbed, MatrixNorm::Frobenius, 1e-4));
+        assert!(!matrix.equals_in_norm(&perturbed, MatrixNorm::Frobenius, 1e-6));
+
+        let wide = Matrix::<Real>::from_str("{{1,2,3}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.distance(&wide, MatrixNorm::One),
+            Err(MatrixError::InvalidNumberOfRows)
+        );
+        assert!(!matrix.equals_in_norm(&wide, MatrixNorm::One, 1.));
+        assert!(matrix.equals_in_norm(&perturbed, MatrixNorm::Spectral, 1e-4));
+        assert!(!matrix.equals_in_norm(&wide, MatrixNorm::MaxAbs, 1.));
+        let square = SquareMatrix::try_from(matrix).unwrap();
+        let perturbed = SquareMatrix::try_from(perturbed).unwrap();
+        assert!(square.equals_in_norm(&perturbed, MatrixNorm::Infinity, 1e-4));
+        assert!(!square.equals(&perturbed, 1e-3));
+    }
+
+    #[test]
+    fn norms_keep_the_double_precision() {
+        let matrix = Matrix::<Real64>::from_str("{{1,2},{3,4}}").unwrap();
+        let mut perturbed = matrix.clone();
+        perturbed[(0, 0)] = Real64::new(1. + 1e-10);
+        for norm in [
+            MatrixNorm::Frobenius,
+            MatrixNorm::MaxAbs,
+            MatrixNorm::Spectral,
+        ] {
+            let distance = matrix.distance(&perturbed, norm).unwrap();
+            assert!(distance.equals(&1e-10, 1e-15), "{norm:?}: {distance}");
+        }
+        assert!(matrix.equals_in_norm(&perturbed, MatrixNorm::Spectral, 1e-9));
+        assert!(!matrix.equals_in_norm(&perturbed, MatrixNorm::Spectral, 1e-12));
+    }
 }
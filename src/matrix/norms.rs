@@ -0,0 +1,115 @@
+use crate::structures::complex::Complex64;
+
+use super::{generic::svd::one_sided_jacobi, MatrixError};
+
+/// [Matrix norm](https://en.wikipedia.org/wiki/Matrix_norm) used by [`AsMatrix::norm`], where
+/// _|a<sub>ij</sub>|_ is the absolute value, or the modulus, of each element.
+///
+/// [`AsMatrix::norm`]: super::AsMatrix::norm
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixNorm {
+    /// Square root of the sum of _|a<sub>ij</sub>|<sup>2</sup>_, i.e., the euclidean norm of the
+    /// elements.
+    Frobenius,
+    /// Maximum sum of _|a<sub>ij</sub>|_ in a column, which is the norm induced by the 1-norm of
+    /// vectors.
+    One,
+    /// Maximum sum of _|a<sub>ij</sub>|_ in a row, which is the norm induced by the ∞-norm of
+    /// vectors.
+    Infinity,
+    /// Maximum _|a<sub>ij</sub>|_. It is not submultiplicative.
+    MaxAbs,
+    /// Largest singular value, which is the norm induced by the euclidean norm of vectors. It is
+    /// computed with the one-sided Jacobi method of [`Matrix::svd`], so it is much more expensive
+    /// than the others.
+    ///
+    /// [`Matrix::svd`]: super::generic::Matrix::svd
+    Spectral,
+}
+
+impl MatrixNorm {
+    /// Computes the norm of the matrix given by its rows, in [`f64`].
+    pub(super) fn compute(&self, data: &[Vec<Complex64>]) -> Result<f64, MatrixError> {
+        let moduli = data
+            .iter()
+            .map(|row| row.iter().map(|z| z.modulus().value()).collect())
+            .collect::<Vec<Vec<f64>>>();
+        let columns = moduli.first().map_or(0, Vec::len);
+        Ok(match self {
+            MatrixNorm::Frobenius => moduli.iter().flatten().map(|x| x * x).sum::<f64>().sqrt(),
+            MatrixNorm::One => (0..columns)
+                .map(|j| moduli.iter().map(|row| row[j]).sum::<f64>())
+                .fold(0., f64::max),
+            MatrixNorm::Infinity => moduli
+                .iter()
+                .map(|row| row.iter().sum::<f64>())
+                .fold(0., f64::max),
+            MatrixNorm::MaxAbs => moduli.iter().flatten().copied().fold(0., f64::max),
+            MatrixNorm::Spectral => {
+                // A = B + iC has the singular values of the real matrix {{B, -C}, {C, B}}, each
+                // one twice.
+                let embedding = data
+                    .iter()
+                    .map(|row| {
+                        let mut real = row.iter().map(|z| z.re().value()).collect::<Vec<f64>>();
+                        real.extend(row.iter().map(|z| -z.im().value()));
+                        real
+                    })
+                    .chain(data.iter().map(|row| {
+                        let mut imaginary =
+                            row.iter().map(|z| z.im().value()).collect::<Vec<f64>>();
+                        imaginary.extend(row.iter().map(|z| z.re().value()));
+                        imaginary
+                    }))
+                    .collect::<Vec<Vec<f64>>>();
+                // The Jacobi method takes the longest side as the columns, and the transpose has
+                // the same singular values.
+                let embedding = match data.len() < columns {
+                    true => embedding,
+                    false => (0..2 * columns)
+                        .map(|j| embedding.iter().map(|row| row[j]).collect())
+                        .collect(),
+                };
+                let (_, values, _) = one_sided_jacobi(embedding, f64::EPSILON)?;
+                values.first().copied().unwrap_or(0.)
+            }
+        })
+    }
+
+    /// Checks if the matrices given by their rows are equal in this norm, relative to their size,
+    /// as [`AsMatrix::equals_in_norm`](super::AsMatrix::equals_in_norm). They are never equal if
+    /// the dimensions do not match.
+    pub(super) fn equals(
+        &self,
+        data: &[Vec<Complex64>],
+        other: &[Vec<Complex64>],
+        tolerance: f64,
+    ) -> bool {
+        if data.len() != other.len()
+            || data
+                .iter()
+                .zip(other)
+                .any(|(row, other_row)| row.len() != other_row.len())
+        {
+            return false;
+        }
+        let difference = data
+            .iter()
+            .zip(other)
+            .map(|(row, other_row)| row.iter().zip(other_row).map(|(x, y)| *x - *y).collect())
+            .collect::<Vec<_>>();
+        // The Frobenius norm bounds the spectral norm from above, and it is enough for the sizes.
+        let size_norm = match self {
+            MatrixNorm::Spectral => MatrixNorm::Frobenius,
+            _ => *self,
+        };
+        let (Ok(distance), Ok(size), Ok(other_size)) = (
+            self.compute(&difference),
+            size_norm.compute(data),
+            size_norm.compute(other),
+        ) else {
+            return false;
+        };
+        distance < tolerance * size.max(other_size).max(1.)
+    }
+}
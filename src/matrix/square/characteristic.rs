@@ -0,0 +1,295 @@
+use crate::{
+    equality::Tolerance,
+    matrix::{error::MatrixError, generic::Matrix, AsMatrix},
+    structures::{polynomials::Polynomial, CommutativeRing, Field, Ring},
+};
+
+use super::SquareMatrix;
+
+impl<R: CommutativeRing + PartialOrd> SquareMatrix<R> {
+    /// Returns the characteristic polynomial _det(xI - A)_ of the matrix, computed with the
+    /// [Berkowitz algorithm](https://en.wikipedia.org/wiki/Samuelson%E2%80%93Berkowitz_algorithm).
+    ///
+    /// It has no divisions, so it works over any [`CommutativeRing`] and it is exact for
+    /// [`Integer`](crate::structures::integers::Integer) entries.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     2   1
+    /// A = 1   2
+    /// ```
+    /// then the result will be the polynomial `+1x^2-4x+3`.
+    ///
+    /// ## Errors
+    /// Returns [`MatrixError::Overflow`] if some coefficient or intermediate product cannot be
+    /// represented, see [`Ring::checked_mul`].
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^4)_.
+    pub fn characteristic_polynomial(&self) -> Result<Polynomial<R>, MatrixError> {
+        // The coefficients are kept from the highest to the lowest degree. The characteristic
+        // polynomial of each leading principal minor is the product of a Toeplitz matrix by the
+        // one of the previous minor.
+        let mut coefficients = vec![R::one()];
+        for r in 0..self.dimension() {
+            let mut toeplitz = vec![R::one(), negate(&self[(r, r)])?];
+            let mut column = (0..r).map(|i| self[(i, r)].clone()).collect::<Vec<R>>();
+            for _ in 0..r {
+                let product = dot((0..r).map(|j| (&self[(r, j)], &column[j])))?;
+                toeplitz.push(negate(&product)?);
+                column = (0..r)
+                    .map(|i| dot((0..r).map(|j| (&self[(i, j)], &column[j]))))
+                    .collect::<Result<_, _>>()?;
+            }
+            coefficients = (0..r + 2)
+                .map(|i| dot((0..=i.min(r)).map(|j| (&toeplitz[i - j], &coefficients[j]))))
+                .collect::<Result<_, _>>()?;
+        }
+        coefficients.reverse();
+        Ok(Polynomial::new(coefficients))
+    }
+
+    /// Returns the matrix _p(A)_, computed with Horner's method.
+    ///
+    /// By the [Cayley-Hamilton theorem](https://en.wikipedia.org/wiki/Cayley%E2%80%93Hamilton_theorem),
+    /// it is the zero matrix when `polynomial` is the characteristic polynomial of _A_.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     1   2
+    /// A = 0   1
+    /// ```
+    /// and the polynomial `+1x^2+1`, then the result will be the matrix
+    /// ```txt
+    ///         2   4
+    /// p(A) =  0   2
+    /// ```
+    ///
+    /// ## Errors
+    /// Returns [`MatrixError::Overflow`] if some entry cannot be represented, see
+    /// [`Ring::checked_mul`].
+    pub fn evaluate_polynomial(
+        &self,
+        polynomial: &Polynomial<R>,
+    ) -> Result<SquareMatrix<R>, MatrixError> {
+        let dimension = self.dimension();
+        let mut result = SquareMatrix::new(dimension, vec![vec![R::zero(); dimension]; dimension]);
+        for coefficient in polynomial.coefficients().iter().rev() {
+            result = (result * self.clone())?;
+            for i in 0..dimension {
+                result[(i, i)] = result[(i, i)]
+                    .checked_add(coefficient)
+                    .ok_or(MatrixError::Overflow)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Returns the sum of the products of the pairs, or [`MatrixError::Overflow`] if some product or
+/// partial sum cannot be represented.
+fn dot<'a, R: Ring + 'a>(pairs: impl Iterator<Item = (&'a R, &'a R)>) -> Result<R, MatrixError> {
+    pairs.into_iter().try_fold(R::zero(), |sum, (a, b)| {
+        a.checked_mul(b)
+            .and_then(|product| sum.checked_add(&product))
+            .ok_or(MatrixError::Overflow)
+    })
+}
+
+/// Returns the opposite of the element, or [`MatrixError::Overflow`] if it cannot be represented.
+fn negate<R: Ring>(element: &R) -> Result<R, MatrixError> {
+    R::zero().checked_sub(element).ok_or(MatrixError::Overflow)
+}
+
+impl<R: Field + PartialOrd> SquareMatrix<R> {
+    /// Returns the minimal polynomial of the matrix, i.e., the monic polynomial _p_ of least
+    /// degree such that _p(A) = 0_. It divides the characteristic polynomial.
+    ///
+    /// It is found as the first power _A<sup>k</sup>_ that is a linear combination of
+    /// _I, A, ..., A<sup>k-1</sup>_, through the null space of the matrix whose columns are the
+    /// entries of those powers.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a pivot is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     2   0   0
+    /// A = 0   2   0
+    ///     0   0   3
+    /// ```
+    /// then the characteristic polynomial is _(x-2)<sup>2</sup>(x-3)_, but the result will be the
+    /// polynomial _(x-2)(x-3)_ = `+1x^2-5x+6`.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^5)_.
+    pub fn minimal_polynomial<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<Polynomial<R>, MatrixError> {
+        let dimension = self.dimension();
+        let mut powers = vec![SquareMatrix::identity(dimension)];
+        for degree in 1..=dimension {
+            let power = (powers[degree - 1].clone() * self.clone())?;
+            powers.push(power);
+            let columns = Matrix::try_from(
+                (0..dimension * dimension)
+                    .map(|entry| {
+                        powers
+                            .iter()
+                            .map(|power| power[(entry / dimension, entry % dimension)].clone())
+                            .collect()
+                    })
+                    .collect::<Vec<Vec<R>>>(),
+            )?;
+            // The previous powers are independent, so the only free column is the last one, and
+            // the vector of the null space is already monic.
+            if let Some(coefficients) = columns.null_space_basis(tolerance)?.pop() {
+                return Ok(Polynomial::new(coefficients));
+            }
+        }
+        Ok(Polynomial::new(vec![R::one()]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{error::MatrixError, square::SquareMatrix},
+        structures::{
+            integers::Integer, polynomials::Polynomial, rationals::Rational, reals::Real,
+        },
+    };
+
+    #[test]
+    fn characteristic_polynomial_should_satisfy_cayley_hamilton() {
+        struct TestCase {
+            id: &'static str,
+            matrix: &'static str,
+            expected: &'static str,
+        }
+        vec![
+            TestCase {
+                id: "2x2 symmetric",
+                matrix: "{{2,1},{1,2}}",
+                expected: "+1x^2-4x+3",
+            },
+            TestCase {
+                id: "3x3 with zero determinant",
+                matrix: "{{1,2,3},{4,5,6},{7,8,9}}",
+                expected: "+1x^3-15x^2-18x",
+            },
+            TestCase {
+                id: "4x4 with negative entries",
+                matrix: "{{2,-1,0,3},{1,0,4,-2},{0,5,-3,1},{-1,2,1,1}}",
+                expected: "+1x^4-20x^2+81x-163",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let matrix = SquareMatrix::<Integer<i64>>::from_str(test.matrix).unwrap();
+            let polynomial = matrix.characteristic_polynomial().unwrap();
+            pretty_assertions::assert_eq!(
+                polynomial,
+                Polynomial::from_str(test.expected).unwrap(),
+                "Test case: {} failed",
+                test.id
+            );
+            let dimension = matrix.dimension();
+            pretty_assertions::assert_eq!(
+                matrix.evaluate_polynomial(&polynomial).unwrap(),
+                SquareMatrix::new(dimension, vec![vec![Integer::new(0); dimension]; dimension]),
+                "Test case: {} failed",
+                test.id
+            );
+        });
+    }
+
+    #[test]
+    fn polynomial_at_matrix() {
+        let matrix = SquareMatrix::<Real>::from_str("{{1,2},{0,1}}").unwrap();
+        let polynomial = Polynomial::from_str("x^2 + 1").unwrap();
+        assert!(matrix
+            .evaluate_polynomial(&polynomial)
+            .unwrap()
+            .equals(&SquareMatrix::from_str("{{2,4},{0,2}}").unwrap(), 1e-6));
+    }
+
+    #[test]
+    fn characteristic_polynomial_should_detect_overflow() {
+        let matrix = SquareMatrix::<Integer<i8>>::from_str("{{100}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.evaluate_polynomial(&Polynomial::from_str("x^2").unwrap()),
+            Err(MatrixError::Overflow)
+        );
+        pretty_assertions::assert_eq!(
+            matrix.evaluate_polynomial(&Polynomial::from_str("x + 100").unwrap()),
+            Err(MatrixError::Overflow)
+        );
+        let matrix = SquareMatrix::<Integer<i8>>::from_str("{{-128}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.characteristic_polynomial(),
+            Err(MatrixError::Overflow)
+        );
+        let matrix = SquareMatrix::<Integer<i8>>::from_str("{{10,20},{20,10}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.characteristic_polynomial(),
+            Err(MatrixError::Overflow)
+        );
+    }
+
+    #[test]
+    fn minimal_polynomial_should_divide_the_characteristic_one() {
+        struct TestCase {
+            id: &'static str,
+            matrix: &'static str,
+            expected: &'static str,
+        }
+        vec![
+            TestCase {
+                id: "Identity",
+                matrix: "{{1,0,0},{0,1,0},{0,0,1}}",
+                expected: "x - 1",
+            },
+            TestCase {
+                id: "Repeated eigenvalue",
+                matrix: "{{2,0,0},{0,2,0},{0,0,3}}",
+                expected: "x^2 - 5x + 6",
+            },
+            TestCase {
+                id: "Jordan block",
+                matrix: "{{2,1,0},{0,2,0},{0,0,2}}",
+                expected: "x^2 - 4x + 4",
+            },
+            TestCase {
+                id: "Equal to the characteristic polynomial",
+                matrix: "{{1,2,3},{4,5,6},{7,8,10}}",
+                expected: "x^3 - 16x^2 - 12x + 3",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let matrix = SquareMatrix::<Rational<i64>>::from_str(test.matrix).unwrap();
+            let minimal = matrix.minimal_polynomial(0.).unwrap();
+            pretty_assertions::assert_eq!(
+                minimal,
+                Polynomial::from_str(test.expected).unwrap(),
+                "Test case: {} failed",
+                test.id
+            );
+            pretty_assertions::assert_eq!(
+                (matrix.characteristic_polynomial().unwrap() % minimal).degree(),
+                None
+            );
+        });
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    equality::Tolerance,
+    matrix::error::MatrixError,
+    structures::{Field, IntegralDomain, Ring},
+    traits::Sqrt,
+};
+
+use super::{determinant::DeterminantMethod, SquareMatrix};
+
+/// [LDL<sup>T</sup> decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition#LDL_decomposition)
+/// of a symmetric [`SquareMatrix`], i.e., the factorization _A = LDL<sup>T</sup>_ where _L_ is a
+/// lower triangular matrix with ones in the diagonal and _D_ is a diagonal matrix.
+///
+/// Unlike the Cholesky decomposition, it has no square roots, so it is exact for
+/// [`Rational`](crate::structures::rationals::Rational) entries, and it exists for some
+/// indefinite matrices, in which case _D_ has negative entries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LdltDecomposition<R: Ring> {
+    lower: SquareMatrix<R>,
+    diagonal: Vec<R>,
+}
+
+impl<R: Ring> LdltDecomposition<R> {
+    /// Returns the lower triangular matrix _L_.
+    pub fn lower(&self) -> &SquareMatrix<R> {
+        &self.lower
+    }
+
+    /// Returns the diagonal of _D_.
+    pub fn diagonal(&self) -> &[R] {
+        &self.diagonal
+    }
+}
+
+impl<R: Ring + PartialOrd> SquareMatrix<R> {
+    /// Checks if the matrix is equal to its transpose, within the `tolerance`.
+    pub fn is_symmetric<T: Tolerance>(&self, tolerance: T) -> bool {
+        (0..self.dimension()).all(|i| (0..i).all(|j| self[(i, j)].equals(&self[(j, i)], tolerance)))
+    }
+}
+
+impl<R: IntegralDomain + PartialOrd> SquareMatrix<R> {
+    /// Checks if the matrix is symmetric and positive definite, with
+    /// [Sylvester's criterion](https://en.wikipedia.org/wiki/Sylvester%27s_criterion): the
+    /// determinants of all the leading principal minors are positive.
+    ///
+    /// The determinants are computed with the Bareiss algorithm, so the check is exact for
+    /// [`Integer`](crate::structures::integers::Integer) entries. For [`Real`] entries,
+    /// [`SquareMatrix::cholesky`] is faster and more stable.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to check the symmetry, and to determine if a
+    ///   determinant is zero.
+    ///
+    /// [`Real`]: crate::structures::reals::Real
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^4)_.
+    pub fn is_positive_definite<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.is_symmetric(tolerance)
+            && (1..=self.dimension()).all(|dimension| {
+                self.leading_principal_minor(dimension)
+                    .and_then(|minor| {
+                        minor.determinant(DeterminantMethod::BareissAlgorithm, tolerance)
+                    })
+                    .is_ok_and(|determinant| {
+                        !determinant.is_zero(tolerance) && determinant > R::zero()
+                    })
+            })
+    }
+}
+
+impl<R: Field + PartialOrd> SquareMatrix<R> {
+    /// Returns the [`LdltDecomposition`] of the symmetric matrix.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to check the symmetry, and to determine if a pivot is
+    ///   zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     1   2
+    /// A = 2   1
+    /// ```
+    /// which is indefinite, then the result will be
+    /// ```txt
+    ///     1   0
+    /// L = 2   1       D = [1, -3]
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::NonSymmetricMatrix`] if the matrix is not symmetric.
+    /// - [`MatrixError::ZeroPivot`] with the index of the pivot, if some pivot is zero but the
+    ///   entries below it are not, as there are no row swaps. For instance, it happens for
+    ///   `{{0,1},{1,0}}`.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn ldlt<T: Tolerance>(&self, tolerance: T) -> Result<LdltDecomposition<R>, MatrixError> {
+        if !self.is_symmetric(tolerance) {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+        let dimension = self.dimension();
+        let mut lower = SquareMatrix::<R>::identity(dimension);
+        let mut diagonal = Vec::<R>::with_capacity(dimension);
+        for j in 0..dimension {
+            let pivot = (0..j).fold(self[(j, j)].clone(), |value, k| {
+                value - lower[(j, k)].clone() * lower[(j, k)].clone() * diagonal[k].clone()
+            });
+            for i in j + 1..dimension {
+                let value = (0..j).fold(self[(i, j)].clone(), |value, k| {
+                    value - lower[(i, k)].clone() * lower[(j, k)].clone() * diagonal[k].clone()
+                });
+                lower[(i, j)] = match (pivot.is_zero(tolerance), value.is_zero(tolerance)) {
+                    (_, true) => R::zero(),
+                    (true, false) => return Err(MatrixError::ZeroPivot(j)),
+                    (false, false) => value / pivot.clone(),
+                };
+            }
+            diagonal.push(pivot);
+        }
+        Ok(LdltDecomposition { lower, diagonal })
+    }
+}
+
+impl<R: Field + PartialOrd + Sqrt> SquareMatrix<R> {
+    /// Returns the lower triangular matrix _L_ of the
+    /// [Cholesky decomposition](https://en.wikipedia.org/wiki/Cholesky_decomposition)
+    /// _A = LL<sup>T</sup>_ of a symmetric positive definite matrix, whose diagonal is positive.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to check the symmetry, and to determine if a pivot is
+    ///   zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///       4    12   -16
+    /// A =  12    37   -43
+    ///     -16   -43    98
+    /// ```
+    /// then the result will be the matrix
+    /// ```txt
+    ///      2   0   0
+    /// L =  6   1   0
+    ///     -8   5   3
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::NonSymmetricMatrix`] if the matrix is not symmetric.
+    /// - [`MatrixError::NotPositiveDefinite`] with the index of the first pivot that is not
+    ///   positive, if the matrix is not positive definite.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_, half of the LU decomposition.
+    pub fn cholesky<T: Tolerance>(&self, tolerance: T) -> Result<SquareMatrix<R>, MatrixError> {
+        if !self.is_symmetric(tolerance) {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+        let dimension = self.dimension();
+        let mut lower = SquareMatrix::new(dimension, vec![vec![R::zero(); dimension]; dimension]);
+        for j in 0..dimension {
+            let pivot = (0..j).fold(self[(j, j)].clone(), |value, k| {
+                value - lower[(j, k)].clone() * lower[(j, k)].clone()
+            });
+            if pivot.is_zero(tolerance) || pivot < R::zero() {
+                return Err(MatrixError::NotPositiveDefinite(j));
+            }
+            let pivot = pivot.sqrt_value();
+            for i in j + 1..dimension {
+                let value = (0..j).fold(self[(i, j)].clone(), |value, k| {
+                    value - lower[(i, k)].clone() * lower[(j, k)].clone()
+                });
+                lower[(i, j)] = value / pivot.clone();
+            }
+            lower[(j, j)] = pivot;
+        }
+        Ok(lower)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{square::SquareMatrix, MatrixError},
+        structures::{integers::Integer, rationals::Rational, reals::Real},
+    };
+
+    #[test]
+    fn cholesky_should_rebuild_the_matrix() {
+        let matrix =
+            SquareMatrix::<Real>::from_str("{{4,12,-16},{12,37,-43},{-16,-43,98}}").unwrap();
+        let lower = matrix.cholesky(1e-6).unwrap();
+        assert!(lower.equals(
+            &SquareMatrix::from_str("{{2,0,0},{6,1,0},{-8,5,3}}").unwrap(),
+            1e-5
+        ));
+        let mut transpose = lower.clone();
+        for i in 0..3 {
+            for j in 0..3 {
+                transpose[(i, j)] = lower[(j, i)];
+            }
+        }
+        assert!((lower * transpose).unwrap().equals(&matrix, 1e-4));
+    }
+
+    #[test]
+    fn cholesky_should_fail_for_non_positive_definite_matrices() {
+        struct TestCase {
+            id: &'static str,
+            matrix: &'static str,
+            expected: MatrixError,
+        }
+        vec![
+            TestCase {
+                id: "Indefinite",
+                matrix: "{{1,2},{2,1}}",
+                expected: MatrixError::NotPositiveDefinite(1),
+            },
+            TestCase {
+                id: "Semidefinite",
+                matrix: "{{1,1,0},{1,1,0},{0,0,1}}",
+                expected: MatrixError::NotPositiveDefinite(1),
+            },
+            TestCase {
+                id: "Negative first pivot",
+                matrix: "{{-1,0},{0,1}}",
+                expected: MatrixError::NotPositiveDefinite(0),
+            },
+            TestCase {
+                id: "Non symmetric",
+                matrix: "{{1,2},{0,1}}",
+                expected: MatrixError::NonSymmetricMatrix,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let matrix = SquareMatrix::<Real>::from_str(test.matrix).unwrap();
+            pretty_assertions::assert_eq!(
+                matrix.cholesky(1e-6),
+                Err(test.expected),
+                "Test case: {} failed",
+                test.id
+            );
+        });
+    }
+
+    #[test]
+    fn ldlt_should_be_exact() {
+        let matrix =
+            SquareMatrix::<Rational<i64>>::from_str("{{4,12,-16},{12,37,-43},{-16,-43,98}}")
+                .unwrap();
+        let ldlt = matrix.ldlt(0.).unwrap();
+        pretty_assertions::assert_eq!(
+            ldlt.lower(),
+            &SquareMatrix::from_str("{{1,0,0},{3,1,0},{-4,5,1}}").unwrap()
+        );
+        pretty_assertions::assert_eq!(
+            ldlt.diagonal(),
+            &[Rational::from(4), Rational::from(1), Rational::from(9)]
+        );
+
+        let indefinite = SquareMatrix::<Rational<i64>>::from_str("{{1,2},{2,1}}").unwrap();
+        pretty_assertions::assert_eq!(
+            indefinite.ldlt(0.).unwrap().diagonal(),
+            &[Rational::from(1), Rational::from(-3)]
+        );
+        let swapped = SquareMatrix::<Rational<i64>>::from_str("{{0,1},{1,0}}").unwrap();
+        pretty_assertions::assert_eq!(swapped.ldlt(0.), Err(MatrixError::ZeroPivot(0)));
+    }
+
+    #[test]
+    fn positive_definiteness_with_sylvester_criterion() {
+        vec![
+            ("{{4,12,-16},{12,37,-43},{-16,-43,98}}", true),
+            ("{{2,-1,0},{-1,2,-1},{0,-1,2}}", true),
+            ("{{1,2},{2,1}}", false),
+            ("{{1,1},{1,1}}", false),
+            ("{{2,1},{0,2}}", false),
+        ]
+        .into_iter()
+        .for_each(|(matrix, expected)| {
+            let matrix = SquareMatrix::<Integer<i64>>::from_str(matrix).unwrap();
+            pretty_assertions::assert_eq!(matrix.is_positive_definite(0.), expected);
+            assert!(matrix.is_symmetric(0.) || !expected);
+        });
+    }
+}
@@ -1,71 +1,142 @@
 use crate::{
-    matrix::{square::SquareMatrix, AsMatrix, MatrixError},
-    structures::Ring,
+    equality::Tolerance,
+    matrix::{
+        square::SquareMatrix,
+        trace::{EliminationTrace, RowOperation},
+        AsMatrix, MatrixError,
+    },
+    structures::IntegralDomain,
 };
 
 use super::Signature;
 
 /// Determinant of an NxN matrix using the [Bareiss algorithm](https://en.wikipedia.org/wiki/Bareiss_algorithm).
-pub(super) fn bareiss_algorithm<R: Ring + PartialOrd>(
+///
+/// Whenever a pivot is zero, its row is swapped with the first row below that has a non-zero
+/// element in the pivot column, changing the sign of the determinant.
+///
+/// ## Errors
+///
+/// Returns [`MatrixError::Overflow`] if some intermediate result cannot be represented, instead
+/// of wrapping or panicking.
+pub(super) fn bareiss_algorithm<R: IntegralDomain + PartialOrd, T: Tolerance>(
     matrix: &SquareMatrix<R>,
-    tolerance: f32,
+    tolerance: T,
 ) -> Result<R, MatrixError> {
-    let mut matrix_cloned = matrix.clone();
+    bareiss_elimination(matrix, tolerance, None)
+}
 
-    // if matrix_cloned.diagonal_is_zero(tolerance) {
-    //     return Err(MatrixError::MatrixError(
-    //         "Matrix has zero elements in diagonal".to_string(),
-    //     ));
-    // }
+/// Same as [`bareiss_algorithm`], but recording every row operation in an [`EliminationTrace`].
+///
+/// Each step of the algorithm, _M<sub>ij</sub> = (M<sub>kk</sub> M<sub>ij</sub> - M<sub>ik</sub>
+/// M<sub>kj</sub>) / M<sub>k-1,k-1</sub>_, is recorded as a [`RowOperation::Scale`] by the
+/// pivot, a [`RowOperation::AddMultiple`] of the pivot row and an exact
+/// [`RowOperation::Divide`] by the previous pivot.
+pub(super) fn bareiss_algorithm_traced<R: IntegralDomain + PartialOrd, T: Tolerance>(
+    matrix: &SquareMatrix<R>,
+    tolerance: T,
+) -> Result<(R, EliminationTrace<R, SquareMatrix<R>>), MatrixError> {
+    let mut trace = EliminationTrace::new(matrix.clone());
+    let determinant = bareiss_elimination(matrix, tolerance, Some(&mut trace))?;
+    Ok((determinant, trace))
+}
+
+/// Performs the Bareiss algorithm with checked operations, recording the row operations in the
+/// `trace`, if any.
+fn bareiss_elimination<R: IntegralDomain + PartialOrd, T: Tolerance>(
+    matrix: &SquareMatrix<R>,
+    tolerance: T,
+    mut trace: Option<&mut EliminationTrace<R, SquareMatrix<R>>>,
+) -> Result<R, MatrixError> {
+    let mut matrix_cloned = matrix.clone();
     let dimension = matrix.dimension();
+    if dimension == 0 {
+        return Ok(R::one());
+    }
     let mut sign = Signature::Even;
+    let mut previous_pivot = R::one();
 
     for k in 0..dimension - 1 {
-        let mut diagonal_element = if k.checked_sub(1).is_none() {
-            R::one()
-        } else {
-            matrix_cloned[(k - 1, k - 1)].to_owned()
-        };
-
-        for i in k..dimension {
-            if diagonal_element.is_zero(tolerance) {
-                matrix_cloned.swap_rows(i, k - 1)?;
-                sign.change();
-                diagonal_element = matrix_cloned[(k - 1, k - 1)].to_owned();
-            } else {
-                break;
+        if matrix_cloned[(k, k)].is_zero(tolerance) {
+            // If there is no non-zero element below the pivot, the matrix is singular.
+            let Some(row) =
+                (k + 1..dimension).find(|row| !matrix_cloned[(*row, k)].is_zero(tolerance))
+            else {
+                return Ok(R::zero());
+            };
+            matrix_cloned.swap_rows(k, row)?;
+            sign.change();
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(RowOperation::Swap {
+                    first: k,
+                    second: row,
+                })?;
             }
         }
 
-        // If after looking for possible non-zero elements, the diagonal_element is still
-        // zero, this means that the matrix is singular.
-        if diagonal_element.is_zero(tolerance) {
-            return Ok(R::zero());
-        }
-
+        let pivot = matrix_cloned[(k, k)].to_owned();
         for i in k + 1..dimension {
+            if let Some(trace) = trace.as_deref_mut() {
+                record_step(trace, &pivot, &matrix_cloned[(i, k)], k, i, &previous_pivot)?;
+            }
             for j in k + 1..dimension {
-                let element = ((matrix_cloned[(i, j)].to_owned()
-                    * matrix_cloned[(k, k)].to_owned())
-                    - (matrix_cloned[(i, k)].to_owned() * matrix_cloned[(k, j)].to_owned()))
-                    / diagonal_element.clone();
+                let element = matrix_cloned[(i, j)]
+                    .checked_mul(&pivot)
+                    .zip(matrix_cloned[(i, k)].checked_mul(&matrix_cloned[(k, j)]))
+                    .and_then(|(a, b)| a.checked_sub(&b))
+                    .and_then(|numerator| numerator.checked_div(&previous_pivot))
+                    .ok_or(MatrixError::Overflow)?;
                 matrix_cloned[(i, j)] = element;
             }
+            matrix_cloned[(i, k)] = R::zero();
         }
+        previous_pivot = pivot;
     }
 
-    Ok(
-        matrix_cloned[(matrix.dimension() - 1, matrix.dimension() - 1)].to_owned()
-            * sign.as_number(),
-    )
+    matrix_cloned[(dimension - 1, dimension - 1)]
+        .checked_mul(&sign.as_number())
+        .ok_or(MatrixError::Overflow)
+}
+
+/// Records the update of the row `i` with the pivot row `k` as row operations, skipping the
+/// ones that do nothing.
+fn record_step<R: IntegralDomain + PartialOrd>(
+    trace: &mut EliminationTrace<R, SquareMatrix<R>>,
+    pivot: &R,
+    factor: &R,
+    k: usize,
+    i: usize,
+    previous_pivot: &R,
+) -> Result<(), MatrixError> {
+    trace.push(RowOperation::Scale {
+        row: i,
+        factor: pivot.clone(),
+    })?;
+    if !factor.is_zero(0.) {
+        trace.push(RowOperation::AddMultiple {
+            factor: -factor.clone(),
+            source: k,
+            target: i,
+        })?;
+    }
+    if k > 0 {
+        trace.push(RowOperation::Divide {
+            row: i,
+            divisor: previous_pivot.clone(),
+        })?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        matrix::square::{determinant::bareiss::bareiss_algorithm, SquareMatrix},
+        matrix::{
+            square::{determinant::bareiss::bareiss_algorithm, SquareMatrix},
+            MatrixError,
+        },
         num_types::FromF32,
-        structures::reals::Real,
+        structures::{integers::Integer, reals::Real},
     };
 
     const TOL: f32 = 1e-12;
@@ -104,6 +175,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bareiss_algorithm_with_zero_pivots_should_not_fail() {
+        let matrix = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::from(0), Integer::from(2), Integer::from(1)],
+            vec![Integer::from(0), Integer::from(1), Integer::from(3)],
+            vec![Integer::from(4), Integer::from(1), Integer::from(2)],
+        ])
+        .unwrap();
+        assert_eq!(bareiss_algorithm(&matrix, TOL), Ok(Integer::from(20)));
+
+        let matrix = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::from(1), Integer::from(2), Integer::from(3)],
+            vec![Integer::from(2), Integer::from(4), Integer::from(1)],
+            vec![Integer::from(1), Integer::from(3), Integer::from(2)],
+        ])
+        .unwrap();
+        assert_eq!(bareiss_algorithm(&matrix, TOL), Ok(Integer::from(5)));
+
+        let matrix = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::from(0), Integer::from(2)],
+            vec![Integer::from(0), Integer::from(1)],
+        ])
+        .unwrap();
+        assert_eq!(bareiss_algorithm(&matrix, TOL), Ok(Integer::from(0)));
+    }
+
+    #[test]
+    fn bareiss_algorithm_should_detect_overflow() {
+        let matrix = SquareMatrix::<Integer<i32>>::from_fn(8, |i, j| {
+            Integer::from(((i * 7 + j * 3) % 11) as i32 + if i == j { 100 } else { 0 })
+        });
+        assert_eq!(bareiss_algorithm(&matrix, TOL), Err(MatrixError::Overflow));
+        let widened = SquareMatrix::<Integer<i128>>::from_fn(8, |i, j| {
+            Integer::from(((i * 7 + j * 3) % 11) as i128 + if i == j { 100 } else { 0 })
+        });
+        let determinant = bareiss_algorithm(&widened, TOL).unwrap();
+        assert!(*determinant.value() > i32::MAX as i128);
+
+        let matrix = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::from(0), Integer::from(2), Integer::from(1)],
+            vec![Integer::from(0), Integer::from(1), Integer::from(3)],
+            vec![Integer::from(4), Integer::from(1), Integer::from(2)],
+        ])
+        .unwrap();
+        assert_eq!(bareiss_algorithm(&matrix, TOL), Ok(Integer::from(20)));
+    }
+
     // #[test]
     // fn large_bareiss_algorithm_should_not_take_long() {
     //     let matrix = SquareMatrix::from_fn(100, |i, j| {
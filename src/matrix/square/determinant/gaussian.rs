@@ -1,26 +1,53 @@
 use crate::{
-    matrix::{square::SquareMatrix, AsMatrix, MatrixError},
-    structures::Ring,
+    equality::Tolerance,
+    matrix::{square::SquareMatrix, trace::EliminationTrace, AsMatrix, MatrixError},
+    structures::IntegralDomain,
 };
 
 /// Gaussian elimination method for calculating the determinant of a matrix.
 ///
+/// The determinant is the product of the diagonal of the reduced matrix, with the sign of the
+/// permutation of rows performed by the pivoting.
+///
 /// Source: <https://en.wikipedia.org/wiki/Gaussian_elimination#Computing_determinants>
-pub(super) fn gaussian_elimination_determinant<R: Ring + PartialOrd>(
+pub(super) fn gaussian_elimination_determinant<R: IntegralDomain + PartialOrd, T: Tolerance>(
     matrix: &SquareMatrix<R>,
-    tolerance: f32,
+    tolerance: T,
 ) -> Result<R, MatrixError> {
-    let reduced = matrix.gaussian_elimination(tolerance)?;
-    let mut determinant = R::one();
+    let (reduced, sign) = matrix.gaussian_elimination_with_signature(tolerance)?;
+    let mut determinant = sign.as_number::<R>();
     for i in 0..reduced.dimension() {
-        determinant = determinant * reduced[(i, i)].to_owned();
+        determinant = determinant
+            .checked_mul(&reduced[(i, i)])
+            .ok_or(MatrixError::Overflow)?;
     }
     Ok(determinant)
 }
 
+/// Same as [`gaussian_elimination_determinant`], but recording every row operation in an
+/// [`EliminationTrace`]. The sign of the determinant is the [`EliminationTrace::signature`].
+pub(super) fn gaussian_elimination_determinant_traced<
+    R: IntegralDomain + PartialOrd,
+    T: Tolerance,
+>(
+    matrix: &SquareMatrix<R>,
+    tolerance: T,
+) -> Result<(R, EliminationTrace<R, SquareMatrix<R>>), MatrixError> {
+    let trace = matrix.gaussian_elimination_traced(tolerance)?;
+    let mut determinant = trace.signature().as_number::<R>();
+    let reduced = trace.result();
+    for i in 0..reduced.dimension() {
+        determinant = determinant
+            .checked_mul(&reduced[(i, i)])
+            .ok_or(MatrixError::Overflow)?;
+    }
+    Ok((determinant, trace))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
+        equality::Equals,
         matrix::square::{determinant::gaussian::gaussian_elimination_determinant, SquareMatrix},
         num_types::FromF32,
         structures::reals::Real,
@@ -62,4 +89,15 @@ mod tests {
             Ok(Real::from_f32(14., TOL))
         );
     }
+
+    #[test]
+    fn gaussian_elimination_determinant_should_take_row_swaps_into_account() {
+        let matrix = SquareMatrix::<Real>::try_from(vec![
+            vec![Real::from_f32(1., TOL), Real::from_f32(2., TOL)],
+            vec![Real::from_f32(3., TOL), Real::from_f32(4., TOL)],
+        ])
+        .unwrap();
+        let determinant = gaussian_elimination_determinant(&matrix, TOL).unwrap();
+        assert!(determinant.equals(&Real::from_f32(-2., TOL), 1e-6));
+    }
 }
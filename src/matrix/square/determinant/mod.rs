@@ -1,11 +1,15 @@
-use crate::{matrix::error::MatrixError, structures::Ring};
+use crate::{
+    equality::Tolerance,
+    matrix::{error::MatrixError, trace::EliminationTrace},
+    structures::{CommutativeRing, IntegralDomain, Ring},
+};
 
 use super::SquareMatrix;
 
 mod bareiss;
-use bareiss::bareiss_algorithm;
+use bareiss::{bareiss_algorithm, bareiss_algorithm_traced};
 mod gaussian;
-use gaussian::gaussian_elimination_determinant;
+use gaussian::{gaussian_elimination_determinant, gaussian_elimination_determinant_traced};
 mod montante;
 use montante::montante_algorithm;
 
@@ -15,6 +19,11 @@ pub enum DeterminantMethod {
     LaplaceExpansion,
     GaussianElimination,
     Optimize,
+    /// Computes the determinant with the Bareiss algorithm, the gaussian elimination and the
+    /// Laplace expansion, and fails if they do not agree within the tolerance. The gaussian
+    /// elimination is left out if the elements are not a [`Field`](crate::structures::Field).
+    /// As it includes the Laplace expansion, it is only suitable for small matrices.
+    Verify,
 }
 
 /// Signature of a permutation.
@@ -50,11 +59,18 @@ impl Signature {
     }
 }
 
-impl<R: Ring + PartialOrd> SquareMatrix<R> {
-    pub fn determinant(
+impl<R: IntegralDomain + PartialOrd> SquareMatrix<R> {
+    /// Computes the determinant with the given method.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::Overflow`] if some intermediate result cannot be represented, e.g.
+    /// for an [`Integer<i32>`](crate::structures::integers::Integer), instead of returning a wrong
+    /// determinant.
+    pub fn determinant<T: Tolerance>(
         &self,
         determinant_method: DeterminantMethod,
-        tolerance: f32,
+        tolerance: T,
     ) -> Result<R, MatrixError> {
         match determinant_method {
             DeterminantMethod::TriangleRule => triangle_rule(self),
@@ -64,9 +80,50 @@ impl<R: Ring + PartialOrd> SquareMatrix<R> {
                 gaussian_elimination_determinant(self, tolerance)
             }
             DeterminantMethod::Optimize => best_determinant_method(self, tolerance),
+            DeterminantMethod::Verify => verified_determinant(self, tolerance),
+        }
+    }
+
+    /// Computes the determinant as [`SquareMatrix::determinant`] does, but also returns the
+    /// [`EliminationTrace`] of the row operations performed.
+    ///
+    /// [`DeterminantMethod::Optimize`] is always traced with the Bareiss algorithm, and
+    /// [`DeterminantMethod::Verify`] returns the trace of the Bareiss algorithm once the other
+    /// methods agree with it.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::UntraceableMethod`] for [`DeterminantMethod::TriangleRule`] and
+    /// [`DeterminantMethod::LaplaceExpansion`], as they do not perform row operations.
+    pub fn determinant_traced<T: Tolerance>(
+        &self,
+        determinant_method: DeterminantMethod,
+        tolerance: T,
+    ) -> Result<(R, EliminationTrace<R, Self>), MatrixError> {
+        match determinant_method {
+            DeterminantMethod::TriangleRule => {
+                Err(MatrixError::UntraceableMethod("triangle rule".to_string()))
+            }
+            DeterminantMethod::LaplaceExpansion => Err(MatrixError::UntraceableMethod(
+                "Laplace expansion".to_string(),
+            )),
+            DeterminantMethod::BareissAlgorithm | DeterminantMethod::Optimize => {
+                bareiss_algorithm_traced(self, tolerance)
+            }
+            DeterminantMethod::GaussianElimination => {
+                gaussian_elimination_determinant_traced(self, tolerance)
+            }
+            DeterminantMethod::Verify => {
+                let (determinant, trace) = bareiss_algorithm_traced(self, tolerance)?;
+                let determinant =
+                    verified_determinant_with(self, ("Bareiss algorithm", determinant), tolerance)?;
+                Ok((determinant, trace))
+            }
         }
     }
+}
 
+impl<R: Ring + PartialOrd> SquareMatrix<R> {
     pub fn leading_principal_minor(&self, dimension: usize) -> Result<Self, MatrixError> {
         if dimension > self.dimension() {
             return Err(MatrixError::InvalidDimension(dimension));
@@ -82,9 +139,9 @@ impl<R: Ring + PartialOrd> SquareMatrix<R> {
     }
 }
 
-fn best_determinant_method<R: Ring + PartialOrd>(
+fn best_determinant_method<R: IntegralDomain + PartialOrd, T: Tolerance>(
     matrix: &SquareMatrix<R>,
-    tolerance: f32,
+    tolerance: T,
 ) -> Result<R, MatrixError> {
     if matrix.dimension() < 4 {
         return triangle_rule(matrix);
@@ -95,42 +152,109 @@ fn best_determinant_method<R: Ring + PartialOrd>(
     montante_algorithm(matrix)
 }
 
-fn triangle_rule<R: Ring + PartialOrd>(matrix: &SquareMatrix<R>) -> Result<R, MatrixError> {
-    match matrix.dimension() {
-        1 => Ok(matrix[(0, 0)].to_owned()),
-        2 => {
-            let a = matrix[(0, 0)].to_owned();
-            let b = matrix[(0, 1)].to_owned();
-            let c = matrix[(1, 0)].to_owned();
-            let d = matrix[(1, 1)].to_owned();
-            Ok(a * d - b * c)
+fn verified_determinant<R: IntegralDomain + PartialOrd, T: Tolerance>(
+    matrix: &SquareMatrix<R>,
+    tolerance: T,
+) -> Result<R, MatrixError> {
+    let bareiss = bareiss_algorithm(matrix, tolerance)?;
+    verified_determinant_with(matrix, ("Bareiss algorithm", bareiss), tolerance)
+}
+
+/// Checks the `reference` determinant against the Laplace expansion and, if `R` is a field, the
+/// Gaussian elimination, which needs the division of a [`Field`](crate::structures::Field).
+fn verified_determinant_with<R: IntegralDomain + PartialOrd, T: Tolerance>(
+    matrix: &SquareMatrix<R>,
+    reference: (&str, R),
+    tolerance: T,
+) -> Result<R, MatrixError> {
+    let mut determinants = vec![reference];
+    if R::IS_FIELD {
+        determinants.push((
+            "Gaussian elimination",
+            gaussian_elimination_determinant(matrix, tolerance)?,
+        ));
+    }
+    determinants.push(("Laplace expansion", montante_algorithm(matrix)?));
+    check_agreement(determinants, tolerance)
+}
+
+/// Returns the first of the given `determinants` if all of them are equal within `tolerance`.
+fn check_agreement<R: Ring, T: Tolerance>(
+    determinants: Vec<(&str, R)>,
+    tolerance: T,
+) -> Result<R, MatrixError> {
+    let mut determinants = determinants.into_iter();
+    let Some((reference_method, reference)) = determinants.next() else {
+        return Err(MatrixError::MatrixError(
+            "No determinant to verify".to_string(),
+        ));
+    };
+    for (method, determinant) in determinants {
+        if !determinant.equals(&reference, tolerance) {
+            return Err(MatrixError::InconsistentDeterminant(format!(
+                "{reference_method} gives {reference} but {method} gives {determinant}"
+            )));
         }
+    }
+    Ok(reference)
+}
+
+fn triangle_rule<R: CommutativeRing + PartialOrd>(
+    matrix: &SquareMatrix<R>,
+) -> Result<R, MatrixError> {
+    let product = |factors: &[(usize, usize)]| {
+        factors.iter().try_fold(R::one(), |product, index| {
+            product.checked_mul(&matrix[*index])
+        })
+    };
+    let determinant = match matrix.dimension() {
+        1 => Some(matrix[(0, 0)].to_owned()),
+        2 => product(&[(0, 0), (1, 1)])
+            .zip(product(&[(0, 1), (1, 0)]))
+            .and_then(|(a, b)| a.checked_sub(&b)),
         3 => {
-            let a = matrix[(0, 0)].to_owned();
-            let b = matrix[(0, 1)].to_owned();
-            let c = matrix[(0, 2)].to_owned();
-            let d = matrix[(1, 0)].to_owned();
-            let e = matrix[(1, 1)].to_owned();
-            let f = matrix[(1, 2)].to_owned();
-            let g = matrix[(2, 0)].to_owned();
-            let h = matrix[(2, 1)].to_owned();
-            let i = matrix[(2, 2)].to_owned();
-            Ok(a.to_owned() * e.to_owned() * i.to_owned()
-                + b.to_owned() * f.to_owned() * g.to_owned()
-                + c.to_owned() * d.to_owned() * h.to_owned()
-                - c * e * g
-                - b * d * i
-                - a * f * h)
+            // Rule of Sarrus: the products of the diagonals minus the ones of the antidiagonals.
+            let diagonals = (0..3).try_fold(R::zero(), |sum, shift| {
+                sum.checked_add(&product(&[
+                    (0, shift),
+                    (1, (shift + 1) % 3),
+                    (2, (shift + 2) % 3),
+                ])?)
+            });
+            let antidiagonals = (0..3).try_fold(R::zero(), |sum, shift| {
+                sum.checked_add(&product(&[
+                    (0, (shift + 2) % 3),
+                    (1, (shift + 1) % 3),
+                    (2, shift),
+                ])?)
+            });
+            diagonals
+                .zip(antidiagonals)
+                .and_then(|(a, b)| a.checked_sub(&b))
         }
-        dim => Err(MatrixError::InvalidDimension(dim)),
-    }
+        dim => return Err(MatrixError::InvalidDimension(dim)),
+    };
+    determinant.ok_or(MatrixError::Overflow)
 }
 
 #[cfg(test)]
 mod tests {
     use std::vec;
 
-    use crate::{matrix::square::SquareMatrix, num_types::FromF32, structures::reals::Real};
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{
+            square::{
+                determinant::{DeterminantMethod, Signature},
+                SquareMatrix,
+            },
+            MatrixError,
+        },
+        num_types::FromF32,
+        structures::{integers::Integer, rationals::Rational, reals::Real},
+    };
 
     const TOL: f32 = 1e-12;
 
@@ -164,6 +288,217 @@ mod tests {
         );
     }
 
+    #[test]
+    fn traced_determinants_should_replay() {
+        let matrix =
+            SquareMatrix::<Rational<i64>>::from_str("{{0,2,1,3},{1,1,0,2},{2,0,1,1},{1,3,2,0}}")
+                .unwrap();
+        let expected = Rational::from(20);
+        [
+            DeterminantMethod::BareissAlgorithm,
+            DeterminantMethod::GaussianElimination,
+            DeterminantMethod::Optimize,
+            DeterminantMethod::Verify,
+        ]
+        .into_iter()
+        .for_each(|method| {
+            let (determinant, trace) = matrix.determinant_traced(method, 0.).unwrap();
+            assert!(
+                determinant.equals(&expected, 0.),
+                "Expected {expected} but got {determinant}"
+            );
+            assert!(trace.replay().unwrap().equals(trace.result(), 0.));
+        });
+
+        let matrix =
+            SquareMatrix::<Integer<i64>>::from_str("{{0,2,1,3},{1,1,0,2},{2,0,1,1},{1,3,2,0}}")
+                .unwrap();
+        let (determinant, trace) = matrix
+            .determinant_traced(DeterminantMethod::BareissAlgorithm, 0.)
+            .unwrap();
+        pretty_assertions::assert_eq!(determinant, Integer::new(20));
+        pretty_assertions::assert_eq!(trace.swaps(), 1);
+        pretty_assertions::assert_eq!(trace.signature(), Signature::Odd);
+        assert!(trace.to_string().starts_with("0 2 1 3 \n"));
+
+        pretty_assertions::assert_eq!(
+            matrix.determinant_traced(DeterminantMethod::LaplaceExpansion, 0.),
+            Err(MatrixError::UntraceableMethod(
+                "Laplace expansion".to_string()
+            ))
+        );
+        pretty_assertions::assert_eq!(
+            matrix.determinant_traced(DeterminantMethod::TriangleRule, 0.),
+            Err(MatrixError::UntraceableMethod("triangle rule".to_string()))
+        );
+    }
+
+    #[test]
+    fn determinant_methods_should_detect_overflow() {
+        let matrix = SquareMatrix::<Integer<i8>>::from_str("{{100,100},{100,-100}}").unwrap();
+        [
+            DeterminantMethod::TriangleRule,
+            DeterminantMethod::BareissAlgorithm,
+            DeterminantMethod::LaplaceExpansion,
+            DeterminantMethod::Optimize,
+            DeterminantMethod::Verify,
+        ]
+        .into_iter()
+        .for_each(|method| {
+            pretty_assertions::assert_eq!(
+                matrix.determinant(method, 0.),
+                Err(MatrixError::Overflow)
+            );
+        });
+        pretty_assertions::assert_eq!(
+            matrix.determinant_traced(DeterminantMethod::BareissAlgorithm, 0.),
+            Err(MatrixError::Overflow)
+        );
+
+        let matrix = SquareMatrix::<Integer<i8>>::from_str("{{10,2,3},{4,10,6},{7,8,10}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.determinant(DeterminantMethod::TriangleRule, 0.),
+            Err(MatrixError::Overflow)
+        );
+        let matrix =
+            SquareMatrix::<Integer<i16>>::from_str("{{10,2,3},{4,10,6},{7,8,10}}").unwrap();
+        [
+            DeterminantMethod::TriangleRule,
+            DeterminantMethod::BareissAlgorithm,
+            DeterminantMethod::LaplaceExpansion,
+        ]
+        .into_iter()
+        .for_each(|method| {
+            pretty_assertions::assert_eq!(matrix.determinant(method, 0.), Ok(Integer::new(410)));
+        });
+    }
+
+    #[test]
+    fn verify_should_skip_gaussian_elimination_outside_fields() {
+        let matrix = SquareMatrix::<Integer<i64>>::from_str("{{2,1},{1,2}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.determinant(DeterminantMethod::Verify, 0.),
+            Ok(Integer::new(3))
+        );
+        let (determinant, trace) = matrix
+            .determinant_traced(DeterminantMethod::Verify, 0.)
+            .unwrap();
+        pretty_assertions::assert_eq!(determinant, Integer::new(3));
+        assert!(trace.replay().unwrap().equals(trace.result(), 0.));
+        pretty_assertions::assert_eq!(
+            matrix.determinant(DeterminantMethod::GaussianElimination, 0.),
+            Err(MatrixError::NotAField("Gaussian elimination".to_string()))
+        );
+    }
+
+    #[test]
+    fn all_determinant_methods_should_agree() {
+        struct TestCase<'a> {
+            id: &'a str,
+            matrix: &'a str,
+            expected: isize,
+        }
+
+        vec![
+            TestCase {
+                id: "Identity",
+                matrix: "{{1,0,0},{0,1,0},{0,0,1}}",
+                expected: 1,
+            },
+            TestCase {
+                id: "Permutation 2x2",
+                matrix: "{{0,1},{1,0}}",
+                expected: -1,
+            },
+            TestCase {
+                id: "Permutation 3x3",
+                matrix: "{{0,1,0},{0,0,1},{1,0,0}}",
+                expected: 1,
+            },
+            TestCase {
+                id: "Permutation 4x4",
+                matrix: "{{0,1,0,0},{1,0,0,0},{0,0,0,1},{0,0,1,0}}",
+                expected: 1,
+            },
+            TestCase {
+                id: "Needs pivoting",
+                matrix: "{{1,2},{3,4}}",
+                expected: -2,
+            },
+            TestCase {
+                id: "Singular",
+                matrix: "{{1,2,3},{4,5,6},{7,8,9}}",
+                expected: 0,
+            },
+            TestCase {
+                id: "Zero pivots 4x4",
+                matrix: "{{0,2,1,3},{1,1,0,2},{2,0,1,1},{1,3,2,0}}",
+                expected: 20,
+            },
+            TestCase {
+                id: "Odd permutation 5x5",
+                matrix: "{{0,0,0,0,2},{0,0,0,3,0},{0,0,1,0,0},{0,5,0,0,0},{7,0,0,0,0}}",
+                expected: 210,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let rational = SquareMatrix::<Rational<i64>>::from_str(test.matrix).unwrap();
+            let real = SquareMatrix::<Real>::from_str(test.matrix).unwrap();
+            let expected = Rational::from(test.expected as i64);
+            let methods = || {
+                let mut methods = vec![
+                    DeterminantMethod::BareissAlgorithm,
+                    DeterminantMethod::GaussianElimination,
+                    DeterminantMethod::LaplaceExpansion,
+                    DeterminantMethod::Optimize,
+                    DeterminantMethod::Verify,
+                ];
+                if rational.dimension() < 4 {
+                    methods.push(DeterminantMethod::TriangleRule);
+                }
+                methods
+            };
+            for method in methods() {
+                let determinant = rational.determinant(method, 0.).unwrap();
+                assert!(
+                    determinant.equals(&expected, 0.),
+                    "Test case {} failed: expected {expected} but got {determinant}",
+                    test.id
+                );
+            }
+            for method in methods() {
+                let determinant = real.determinant(method, 1e-6).unwrap();
+                assert!(
+                    determinant.equals(&Real::new(test.expected as f32), 1e-4),
+                    "Test case {} failed: expected {} but got {determinant}",
+                    test.id,
+                    test.expected
+                );
+            }
+        });
+    }
+
+    #[test]
+    fn check_agreement_should_fail_on_mismatch() {
+        pretty_assertions::assert_eq!(
+            super::check_agreement(
+                vec![("First", Real::new(1.)), ("Second", Real::new(1.00001))],
+                1e-3
+            ),
+            Ok(Real::new(1.))
+        );
+        pretty_assertions::assert_eq!(
+            super::check_agreement(
+                vec![("First", Real::new(1.)), ("Second", Real::new(-1.))],
+                1e-3
+            ),
+            Err(MatrixError::InconsistentDeterminant(
+                "First gives +1 but Second gives -1".to_string()
+            ))
+        );
+    }
+
     // #[test]
     // fn determinant_should_not_last_long() {
     //     let huge_matrix = SquareMatrix::from_fn(10, |i, j| {
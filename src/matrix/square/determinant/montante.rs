@@ -1,6 +1,6 @@
 use crate::{
     matrix::{square::SquareMatrix, MatrixError},
-    structures::Ring,
+    structures::CommutativeRing,
 };
 
 use super::Signature;
@@ -13,7 +13,8 @@ use super::Signature;
 /// - <https://informatika.stei.itb.ac.id/~rinaldi.munir/Matdis/2016-2017/Makalah2016/Makalah-Matdis-2016-051.pdf>
 ///
 /// ## Errors
-/// The function should never panic, but it may return an error if the matrix is not square.
+/// The function should never panic, but it may return an error if the matrix is not square, or
+/// [`MatrixError::Overflow`] if some intermediate result cannot be represented.
 ///
 /// ## Examples
 /// ```txt
@@ -25,7 +26,7 @@ use super::Signature;
 ///
 /// ## Time complexity
 /// The complexity of this algorithm is O(n!). Hence, it is not very good for big matrices.
-pub(super) fn montante_algorithm<R: Ring + PartialOrd>(
+pub(super) fn montante_algorithm<R: CommutativeRing + PartialOrd>(
     matrix: &SquareMatrix<R>,
 ) -> Result<R, MatrixError> {
     if matrix.dimension() == 1 {
@@ -34,10 +35,14 @@ pub(super) fn montante_algorithm<R: Ring + PartialOrd>(
     let mut determinant = R::zero();
     let mut sign = Signature::Even;
     for column in 0..matrix.dimension() {
+        let minor_determinant = montante_algorithm(&matrix.minor(0, column)?)?;
+        determinant = sign
+            .as_number::<R>()
+            .checked_mul(&matrix[(0, column)])
+            .and_then(|cofactor| cofactor.checked_mul(&minor_determinant))
+            .and_then(|term| determinant.checked_add(&term))
+            .ok_or(MatrixError::Overflow)?;
         sign.change();
-        let minor = matrix.minor(0, column)?;
-        determinant = determinant
-            + sign.as_number::<R>() * matrix[(0, column)].clone() * montante_algorithm(&minor)?;
     }
     Ok(determinant)
 }
@@ -78,6 +83,13 @@ mod tests {
         let time = start.elapsed().as_millis();
         println!("Montante's method took {} ms", time);
         assert!(time < 5000);
-        pretty_assertions::assert_eq!(computed, Ok(Integer::from(-1)));
+        pretty_assertions::assert_eq!(computed, Ok(Integer::from(1)));
+
+        let matrix = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::from(1), Integer::from(2)],
+            vec![Integer::from(3), Integer::from(4)],
+        ])
+        .unwrap();
+        pretty_assertions::assert_eq!(montante_algorithm(&matrix), Ok(Integer::from(-2)));
     }
 }
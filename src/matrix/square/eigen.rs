@@ -0,0 +1,529 @@
+use std::cmp::Ordering;
+
+use crate::{
+    equality::Tolerance,
+    identities::{One, Zero},
+    matrix::{error::MatrixError, generic::Matrix},
+    structures::{complex::Complex, reals::Real, Ring},
+};
+
+use super::SquareMatrix;
+
+/// Maximum number of QR iterations to deflate each eigenvalue.
+const MAX_QR_ITERATIONS: usize = 100;
+
+/// Maximum number of sweeps of the Jacobi method.
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// The eigenvalues of a [`SquareMatrix`] together with its eigenvectors, which are the columns
+/// of a [`Matrix`] in the same order as the eigenvalues and have norm one.
+///
+/// It is returned by [`SquareMatrix::eigen_decomposition`], over [`Complex`] numbers, and by
+/// [`SquareMatrix::symmetric_eigen_decomposition`], over [`Real`] numbers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenDecomposition<R: Ring> {
+    eigenvalues: Vec<R>,
+    eigenvectors: Matrix<R>,
+}
+
+impl<R: Ring> EigenDecomposition<R> {
+    /// Returns the eigenvalues, repeated by their algebraic multiplicity.
+    pub fn eigenvalues(&self) -> &[R] {
+        &self.eigenvalues
+    }
+
+    /// Returns the matrix whose `i`-th column is the eigenvector of the `i`-th eigenvalue.
+    pub fn eigenvectors(&self) -> &Matrix<R> {
+        &self.eigenvectors
+    }
+}
+
+fn scaled(value: Complex, factor: f32) -> Complex {
+    value * Complex::from((factor, 0.))
+}
+
+fn norm(values: impl Iterator<Item = Complex>) -> f32 {
+    values
+        .map(|value| value.modulus().value().powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Reduces `h` to upper Hessenberg form with Householder reflections, accumulating them in `q`,
+/// so that the original matrix is _Q·H·Q<sup>*</sup>_.
+fn hessenberg(h: &mut [Vec<Complex>], q: &mut [Vec<Complex>]) {
+    let n = h.len();
+    for k in 0..n.saturating_sub(2) {
+        let length = norm((k + 1..n).map(|i| h[i][k]));
+        if norm((k + 2..n).map(|i| h[i][k])) == 0. {
+            continue;
+        }
+        let first = h[k + 1][k];
+        let phase = match first.modulus().value() {
+            0. => Complex::one(),
+            modulus => scaled(first, 1. / modulus),
+        };
+        let mut v = (k + 1..n).map(|i| h[i][k]).collect::<Vec<_>>();
+        v[0] = v[0] + scaled(phase, length);
+        let v_norm = norm(v.iter().copied());
+        v.iter_mut()
+            .for_each(|element| *element = scaled(*element, 1. / v_norm));
+
+        // H = (I - 2vv*) H (I - 2vv*) and Q = Q (I - 2vv*)
+        let products = (0..n)
+            .map(|j| {
+                (0..v.len()).fold(Complex::zero(), |sum, i| {
+                    sum + v[i].conjugate() * h[k + 1 + i][j]
+                })
+            })
+            .collect::<Vec<_>>();
+        for (row, vi) in h[k + 1..].iter_mut().zip(v.iter()) {
+            for (element, product) in row.iter_mut().zip(products.iter()) {
+                *element = *element - scaled(*vi * *product, 2.);
+            }
+        }
+        for row in h.iter_mut().chain(q.iter_mut()) {
+            let product = (0..v.len()).fold(Complex::zero(), |sum, j| sum + row[k + 1 + j] * v[j]);
+            for j in 0..v.len() {
+                row[k + 1 + j] = row[k + 1 + j] - scaled(product * v[j].conjugate(), 2.);
+            }
+        }
+    }
+}
+
+/// Returns the eigenvalue of the 2x2 matrix `[[a, b], [c, d]]` that is closer to `d`, i.e., the
+/// Wilkinson shift.
+fn wilkinson_shift(a: Complex, b: Complex, c: Complex, d: Complex) -> Complex {
+    let half_difference = scaled(a - d, 0.5);
+    let discriminant = half_difference * half_difference + b * c;
+    let modulus = discriminant.modulus().value().sqrt();
+    let angle = discriminant.im().value().atan2(discriminant.re().value()) / 2.;
+    let root = Complex::from((modulus * angle.cos(), modulus * angle.sin()));
+    let mean = scaled(a + d, 0.5);
+    let (first, second) = (mean + root, mean - root);
+    match (first - d).modulus() < (second - d).modulus() {
+        true => first,
+        false => second,
+    }
+}
+
+/// Reduces the upper Hessenberg matrix `h` to the upper triangular Schur form with the shifted
+/// QR algorithm, accumulating the Givens rotations in `q`.
+fn schur<T: Tolerance>(
+    h: &mut [Vec<Complex>],
+    q: &mut [Vec<Complex>],
+    tolerance: T,
+) -> Result<(), MatrixError> {
+    let n = h.len();
+    let scale = norm(h.iter().flatten().copied()).max(f32::MIN_POSITIVE);
+    let mut high = n;
+    let mut iterations = 0;
+    while high > 1 {
+        let mut low = high - 1;
+        while low > 0 {
+            let diagonal = (h[low][low].modulus() + h[low - 1][low - 1].modulus()).value();
+            let diagonal = if diagonal == 0. { scale } else { diagonal };
+            if h[low][low - 1].modulus().value() as f64 <= tolerance.as_f64() * diagonal as f64 {
+                h[low][low - 1] = Complex::zero();
+                break;
+            }
+            low -= 1;
+        }
+        if low == high - 1 {
+            high -= 1;
+            iterations = 0;
+            continue;
+        }
+        iterations += 1;
+        if iterations > MAX_QR_ITERATIONS {
+            return Err(MatrixError::NotConverged(MAX_QR_ITERATIONS));
+        }
+
+        let last = high - 1;
+        let mut shift = wilkinson_shift(
+            h[last - 1][last - 1],
+            h[last - 1][last],
+            h[last][last - 1],
+            h[last][last],
+        );
+        if iterations % 10 == 0 {
+            // Exceptional shift, to break the cycles of the Wilkinson shift
+            shift = shift + scaled(Complex::one(), h[last][last - 1].modulus().value());
+        }
+        for (i, row) in h.iter_mut().enumerate().take(high).skip(low) {
+            row[i] = row[i] - shift;
+        }
+        let mut rotations = Vec::with_capacity(high - low - 1);
+        for k in low..last {
+            let (x, y) = (h[k][k], h[k + 1][k]);
+            let r = norm([x, y].into_iter());
+            let (c, s) = match r {
+                0. => (Complex::one(), Complex::zero()),
+                r => (scaled(x, 1. / r), scaled(y, 1. / r)),
+            };
+            let (top, bottom) = h.split_at_mut(k + 1);
+            for (upper, lower) in top[k][k..].iter_mut().zip(bottom[0][k..].iter_mut()) {
+                (*upper, *lower) = (
+                    c.conjugate() * *upper + s.conjugate() * *lower,
+                    c * *lower - s * *upper,
+                );
+            }
+            rotations.push((k, c, s));
+        }
+        for (k, c, s) in rotations {
+            let rows = (k + 2).min(high);
+            for row in h[..rows].iter_mut().chain(q.iter_mut()) {
+                let (left, right) = (row[k], row[k + 1]);
+                row[k] = left * c + right * s;
+                row[k + 1] = right * c.conjugate() - left * s.conjugate();
+            }
+        }
+        for (i, row) in h.iter_mut().enumerate().take(high).skip(low) {
+            row[i] = row[i] + shift;
+        }
+    }
+    Ok(())
+}
+
+impl<R> SquareMatrix<R>
+where
+    R: Ring + PartialOrd + Into<Complex>,
+{
+    /// Returns the eigenvalues and the eigenvectors of the matrix, which can have [`Real`],
+    /// [`Complex`] or [`Rational`](crate::structures::rationals::Rational) entries.
+    ///
+    /// The matrix is reduced to upper Hessenberg form with Householder reflections, and then to
+    /// its [Schur form](https://en.wikipedia.org/wiki/Schur_decomposition) _A = QTQ<sup>*</sup>_
+    /// with the QR algorithm with Wilkinson shifts. The eigenvalues are the diagonal of _T_,
+    /// sorted by their real part and then by their imaginary part, and the eigenvectors are
+    /// found by back substitution in _T_.
+    ///
+    /// For symmetric matrices, [`SquareMatrix::symmetric_eigen_decomposition`] guarantees real
+    /// eigenvalues and eigenvectors.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance, relative to the neighbouring diagonal entries, used to
+    ///   determine if a subdiagonal entry is zero. As the computations are made with [`Complex`],
+    ///   in single precision, a tolerance below about `1e-7` cannot be reached.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     0  -1
+    /// A = 1   0
+    /// ```
+    /// then the eigenvalues will be `[-1i, +1i]`, with eigenvectors `(1, -i)/√2` and `(1, i)/√2`
+    /// up to a unit factor.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::NotConverged`] if some eigenvalue is not found after
+    /// 100 iterations.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn eigen_decomposition<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<EigenDecomposition<Complex>, MatrixError> {
+        let n = self.dimension();
+        let mut t = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)].clone().into()).collect())
+            .collect::<Vec<Vec<Complex>>>();
+        let mut q = SquareMatrix::<Complex>::identity(n).data;
+        hessenberg(&mut t, &mut q);
+        schur(&mut t, &mut q, tolerance)?;
+
+        // Eigenvectors of T by back substitution. Repeated eigenvalues would divide by zero, so
+        // the difference is replaced by a small number, as LAPACK does.
+        let smallest = f32::EPSILON * norm(t.iter().flatten().copied()).max(f32::MIN_POSITIVE);
+        let mut pairs = (0..n)
+            .map(|k| {
+                let mut v = vec![Complex::zero(); n];
+                v[k] = Complex::one();
+                for i in (0..k).rev() {
+                    let sum = (i + 1..=k).fold(Complex::zero(), |sum, j| sum + t[i][j] * v[j]);
+                    let mut difference = t[i][i] - t[k][k];
+                    if difference.modulus().value() < smallest {
+                        difference = Complex::from((smallest, 0.));
+                    }
+                    v[i] = -(sum / difference);
+                }
+                let vector = (0..n)
+                    .map(|i| (0..=k).fold(Complex::zero(), |sum, j| sum + q[i][j] * v[j]))
+                    .collect::<Vec<_>>();
+                let length = norm(vector.iter().copied());
+                let vector = vector.into_iter().map(|x| scaled(x, 1. / length)).collect();
+                (t[k][k], vector)
+            })
+            .collect::<Vec<(Complex, Vec<Complex>)>>();
+        pairs.sort_by(|(a, _), (b, _)| {
+            (a.re().value(), a.im().value())
+                .partial_cmp(&(b.re().value(), b.im().value()))
+                .unwrap_or(Ordering::Equal)
+        });
+        let (eigenvalues, vectors): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        Ok(EigenDecomposition {
+            eigenvalues,
+            eigenvectors: Matrix {
+                data: (0..n)
+                    .map(|i| vectors.iter().map(|vector| vector[i]).collect())
+                    .collect(),
+            },
+        })
+    }
+
+    /// Returns the eigenvalues of the matrix. See [`SquareMatrix::eigen_decomposition`].
+    pub fn eigenvalues<T: Tolerance>(&self, tolerance: T) -> Result<Vec<Complex>, MatrixError> {
+        Ok(self.eigen_decomposition(tolerance)?.eigenvalues)
+    }
+}
+
+impl SquareMatrix<Real> {
+    /// Returns the eigenvalues and the eigenvectors of a symmetric matrix, computed with the
+    /// cyclic [Jacobi method](https://en.wikipedia.org/wiki/Jacobi_eigenvalue_algorithm).
+    ///
+    /// Every off-diagonal entry is annihilated in turn by a plane rotation, until they are
+    /// negligible. As the rotations are real, the eigenvalues are real, sorted in increasing
+    /// order, and the eigenvectors are orthonormal.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to check the symmetry, and to stop when the norm of the
+    ///   off-diagonal entries, relative to the norm of the matrix, is below it. As the
+    ///   computations are made in single precision, a tolerance below about `1e-7` cannot be
+    ///   reached.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     2   1
+    /// A = 1   2
+    /// ```
+    /// then the eigenvalues will be `[1, 3]`, with eigenvectors `(1, -1)/√2` and `(1, 1)/√2`.
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::NonSymmetricMatrix`] if the matrix is not symmetric.
+    /// - [`MatrixError::NotConverged`] if the method has not converged after
+    ///   100 sweeps.
+    ///
+    /// ## Complexity
+    /// Each sweep is _O(n^3)_, and the convergence is quadratic.
+    pub fn symmetric_eigen_decomposition<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<EigenDecomposition<Real>, MatrixError> {
+        if !self.is_symmetric(tolerance) {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+        let n = self.dimension();
+        let mut a = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)].value()).collect())
+            .collect::<Vec<Vec<f32>>>();
+        let mut v = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1. } else { 0. }).collect())
+            .collect::<Vec<Vec<f32>>>();
+        let scale = a.iter().flatten().map(|x| x * x).sum::<f32>().sqrt();
+
+        let mut converged = false;
+        for _ in 0..MAX_JACOBI_SWEEPS {
+            let off_diagonal = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum::<f32>()
+                .sqrt();
+            if off_diagonal as f64 <= tolerance.as_f64() * scale as f64 {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] == 0. {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (2. * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.).sqrt());
+                    let c = 1. / (t * t + 1.).sqrt();
+                    let s = t * c;
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (left, right) = (row[p], row[q]);
+                        row[p] = c * left - s * right;
+                        row[q] = s * left + c * right;
+                    }
+                    let (top, bottom) = a.split_at_mut(q);
+                    for (upper, lower) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+                        (*upper, *lower) = (c * *upper - s * *lower, s * *upper + c * *lower);
+                    }
+                }
+            }
+        }
+        if !converged {
+            return Err(MatrixError::NotConverged(MAX_JACOBI_SWEEPS));
+        }
+
+        let mut order = (0..n).collect::<Vec<usize>>();
+        order.sort_by(|&i, &j| a[i][i].partial_cmp(&a[j][j]).unwrap_or(Ordering::Equal));
+        Ok(EigenDecomposition {
+            eigenvalues: order.iter().map(|&k| Real::new(a[k][k])).collect(),
+            eigenvectors: Matrix {
+                data: v
+                    .iter()
+                    .map(|row| order.iter().map(|&k| Real::new(row[k])).collect())
+                    .collect(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{generic::Matrix, square::SquareMatrix, AsMatrix, MatrixError},
+        structures::{complex::Complex, reals::Real, Ring},
+    };
+
+    /// Asserts that _A·v = λ·v_ for every column _v_ of the eigenvectors.
+    fn assert_eigenpairs<R: Ring + PartialOrd>(
+        matrix: &Matrix<R>,
+        eigenvalues: &[R],
+        eigenvectors: &Matrix<R>,
+        tolerance: f32,
+        id: &str,
+    ) {
+        let product = (matrix.clone() * eigenvectors.clone()).unwrap();
+        for (k, eigenvalue) in eigenvalues.iter().enumerate() {
+            for i in 0..matrix.rows() {
+                assert!(
+                    product[(i, k)].equals(
+                        &(eigenvalue.clone() * eigenvectors[(i, k)].clone()),
+                        tolerance
+                    ),
+                    "Test case: {} failed for the eigenvalue {}",
+                    id,
+                    eigenvalue
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn eigen_decomposition_of_real_and_complex_matrices() {
+        struct TestCase {
+            id: &'static str,
+            matrix: SquareMatrix<Complex>,
+            expected: Vec<(f32, f32)>,
+        }
+        let complex = |matrix: &str| {
+            let real = SquareMatrix::<Real>::from_str(matrix).unwrap();
+            let n = real.dimension();
+            SquareMatrix::new(
+                n,
+                (0..n)
+                    .map(|i| (0..n).map(|j| Complex::from(real[(i, j)])).collect())
+                    .collect(),
+            )
+        };
+        vec![
+            TestCase {
+                id: "Triangular",
+                matrix: complex("{{2,0,0},{1,3,0},{4,5,1}}"),
+                expected: vec![(1., 0.), (2., 0.), (3., 0.)],
+            },
+            TestCase {
+                id: "Rotation",
+                matrix: complex("{{0,-1},{1,0}}"),
+                expected: vec![(0., -1.), (0., 1.)],
+            },
+            TestCase {
+                id: "Defective",
+                matrix: complex("{{2,1},{0,2}}"),
+                expected: vec![(2., 0.), (2., 0.)],
+            },
+            TestCase {
+                id: "General",
+                matrix: complex("{{4,-1,2,0},{3,1,0,1},{0,2,-1,5},{1,0,2,2}}"),
+                expected: vec![
+                    (-2.30356, 0.),
+                    (1.53349, -0.84598),
+                    (1.53349, 0.84598),
+                    (5.23658, 0.),
+                ],
+            },
+            TestCase {
+                id: "Complex entries",
+                matrix: SquareMatrix::new(
+                    2,
+                    vec![
+                        vec![Complex::from((1., 1.)), Complex::from((2., 0.))],
+                        vec![Complex::from((0., 0.)), Complex::from((3., -1.))],
+                    ],
+                ),
+                expected: vec![(1., 1.), (3., -1.)],
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let decomposition = test.matrix.eigen_decomposition(1e-6).unwrap();
+            decomposition
+                .eigenvalues()
+                .iter()
+                .zip(test.expected)
+                .for_each(|(computed, expected)| {
+                    assert!(
+                        computed.equals(&Complex::from(expected), 1e-4),
+                        "Test case: {} failed, got {}",
+                        test.id,
+                        computed
+                    )
+                });
+            assert_eigenpairs(
+                &Matrix::from(test.matrix),
+                decomposition.eigenvalues(),
+                decomposition.eigenvectors(),
+                1e-4,
+                test.id,
+            );
+        });
+
+        let real = SquareMatrix::<Real>::from_str("{{1,2,3},{0,4,5},{1,0,6}}").unwrap();
+        let eigenvalues = real.eigenvalues(1e-6).unwrap();
+        let trace = eigenvalues
+            .iter()
+            .fold(Complex::from((0., 0.)), |sum, eigenvalue| sum + *eigenvalue);
+        assert!(trace.equals(&Complex::from((11., 0.)), 1e-4));
+    }
+
+    #[test]
+    fn symmetric_eigen_decomposition_should_be_real() {
+        let matrix = SquareMatrix::<Real>::from_str("{{4,1,2},{1,3,0},{2,0,5}}").unwrap();
+        let decomposition = matrix.symmetric_eigen_decomposition(1e-6).unwrap();
+        let eigenvalues = decomposition.eigenvalues();
+        assert!(eigenvalues.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eigenpairs(
+            &Matrix::from(matrix),
+            eigenvalues,
+            decomposition.eigenvectors(),
+            1e-4,
+            "Symmetric 3x3",
+        );
+        let vectors = decomposition.eigenvectors();
+        let gram = (vectors.transpose() * vectors.clone()).unwrap();
+        assert!(gram.equals(&Matrix::from(SquareMatrix::<Real>::identity(3)), 1e-5));
+
+        let matrix = SquareMatrix::<Real>::from_str("{{2,1},{1,2}}").unwrap();
+        let decomposition = matrix.symmetric_eigen_decomposition(1e-6).unwrap();
+        assert!(decomposition.eigenvalues()[0].equals(&Real::new(1.), 1e-5));
+        assert!(decomposition.eigenvalues()[1].equals(&Real::new(3.), 1e-5));
+
+        let matrix = SquareMatrix::<Real>::from_str("{{1,2},{0,1}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.symmetric_eigen_decomposition(1e-6),
+            Err(MatrixError::NonSymmetricMatrix)
+        );
+    }
+}
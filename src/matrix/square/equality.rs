@@ -1,9 +1,12 @@
-use crate::{equality::Equals, structures::Ring};
+use crate::{
+    equality::{Equals, Tolerance},
+    structures::Ring,
+};
 
 use super::SquareMatrix;
 
 impl<R: Ring + PartialOrd> Equals for SquareMatrix<R> {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
         if self.dimension() != rhs.dimension() {
             return false;
         }
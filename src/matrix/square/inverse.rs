@@ -1,10 +1,195 @@
-use crate::{matrix::error::MatrixError, structures::Ring};
+use crate::{
+    equality::Tolerance,
+    matrix::{error::MatrixError, AsMatrix},
+    structures::Field,
+};
 
 use super::SquareMatrix;
 
-impl<R: Ring> SquareMatrix<R> {
-    pub fn inverse_gauss_jordan(&self, tolerance: f32) -> Result<SquareMatrix<R>, MatrixError> {
-        // let reduced = self.gaussian_elimination(tolerance)?;
-        todo!()
+impl<R: Field + PartialOrd> SquareMatrix<R> {
+    /// Returns the inverse of the matrix, computed with the
+    /// [Gauss-Jordan method](https://en.wikipedia.org/wiki/Gaussian_elimination#Finding_the_inverse_of_a_matrix).
+    ///
+    /// The matrix is reduced to the identity with partial pivoting, applying the very same row
+    /// operations to an identity matrix, which becomes the inverse.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a pivot is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     2   1
+    /// M = 1   1
+    /// ```
+    /// then the result will be the matrix
+    /// ```txt
+    ///      1  -1
+    /// M'= -1   2
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::SingularMatrix`] if some column has no pivot greater than
+    /// `tolerance`, i.e., the matrix is singular.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn inverse_gauss_jordan<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<SquareMatrix<R>, MatrixError> {
+        let dimension = self.dimension();
+        let mut matrix = self.clone();
+        let mut inverse = SquareMatrix::<R>::identity(dimension);
+        for column in 0..dimension {
+            let mut pivot_row = column;
+            for row in column + 1..dimension {
+                if matrix[(row, column)].abs_value() > matrix[(pivot_row, column)].abs_value() {
+                    pivot_row = row;
+                }
+            }
+            if matrix[(pivot_row, column)].is_zero(tolerance) {
+                return Err(MatrixError::SingularMatrix);
+            }
+            matrix.swap_rows(column, pivot_row)?;
+            inverse.swap_rows(column, pivot_row)?;
+
+            let pivot_inverse = matrix[(column, column)].inverse_multiplication();
+            for j in 0..dimension {
+                matrix[(column, j)] = matrix[(column, j)].to_owned() * pivot_inverse.to_owned();
+                inverse[(column, j)] = inverse[(column, j)].to_owned() * pivot_inverse.to_owned();
+            }
+
+            for row in 0..dimension {
+                if row == column {
+                    continue;
+                }
+                let factor = matrix[(row, column)].to_owned();
+                if factor.is_zero(tolerance) {
+                    continue;
+                }
+                for j in 0..dimension {
+                    let new_value = matrix[(row, j)].to_owned()
+                        - factor.to_owned() * matrix[(column, j)].to_owned();
+                    matrix[(row, j)] = new_value;
+                    let new_value = inverse[(row, j)].to_owned()
+                        - factor.to_owned() * inverse[(column, j)].to_owned();
+                    inverse[(row, j)] = new_value;
+                }
+            }
+        }
+        Ok(inverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{square::SquareMatrix, MatrixError},
+        structures::{complex::Complex, rationals::Rational, reals::Real, Field},
+    };
+
+    struct TestCase<'a> {
+        id: &'a str,
+        matrix: &'a str,
+        expected: &'a str,
+    }
+
+    fn perform_test<R: Field + PartialOrd>(
+        test: TestCase<'_>,
+        builder: fn(&str) -> Result<SquareMatrix<R>, MatrixError>,
+        tolerance: f32,
+    ) {
+        let matrix = builder(test.matrix).unwrap();
+        let expected = builder(test.expected).unwrap();
+        let inverse = matrix.inverse_gauss_jordan(tolerance).unwrap();
+        assert!(
+            inverse.equals(&expected, tolerance),
+            "Test case: {} failed. Expected\n{expected}but got\n{inverse}",
+            test.id,
+        );
+        let identity = (matrix * inverse).unwrap();
+        assert!(
+            identity.equals(&SquareMatrix::identity(identity.dimension()), tolerance),
+            "Test case: {} failed. The product is not the identity:\n{identity}",
+            test.id,
+        );
+    }
+
+    #[test]
+    fn inverse_of_rational_matrix_should_be_exact() {
+        vec![
+            TestCase {
+                id: "Simple 2x2",
+                matrix: "{{2,1},{1,1}}",
+                expected: "{{1,-1},{-1,2}}",
+            },
+            TestCase {
+                id: "Needs pivoting",
+                matrix: "{{0,1},{1,0}}",
+                expected: "{{0,1},{1,0}}",
+            },
+            TestCase {
+                id: "Simple 3x3",
+                matrix: "{{1,2,3},{0,1,4},{5,6,0}}",
+                expected: "{{-24,18,5},{20,-15,-4},{-5,4,1}}",
+            },
+            TestCase {
+                id: "Fractions 3x3",
+                matrix: "{{1/2,1/3,1/4},{1/3,1/4,1/5},{1/4,1/5,1/6}}",
+                expected: "{{72,-240,180},{-240,900,-720},{180,-720,600}}",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| perform_test(test, SquareMatrix::<Rational<i64>>::from_str, 0.));
+    }
+
+    #[test]
+    fn inverse_of_real_matrix_should_not_fail() {
+        vec![
+            TestCase {
+                id: "Simple 2x2",
+                matrix: "{{4,7},{2,6}}",
+                expected: "{{0.6,-0.7},{-0.2,0.4}}",
+            },
+            TestCase {
+                id: "Simple 3x3",
+                matrix: "{{1,2,3},{0,1,4},{5,6,0}}",
+                expected: "{{-24,18,5},{20,-15,-4},{-5,4,1}}",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| perform_test(test, SquareMatrix::<Real>::from_str, 1e-4));
+    }
+
+    #[test]
+    fn inverse_of_complex_matrix_should_not_fail() {
+        let matrix = SquareMatrix::<Complex>::try_from(vec![
+            vec![Complex::from((1., 1.)), Complex::from((0., 2.))],
+            vec![Complex::from((3., 0.)), Complex::from((1., -1.))],
+        ])
+        .unwrap();
+        let inverse = matrix.inverse_gauss_jordan(1e-6).unwrap();
+        let identity = (matrix * inverse).unwrap();
+        assert!(identity.equals(&SquareMatrix::identity(2), 1e-5));
+    }
+
+    #[test]
+    fn inverse_of_singular_matrix_should_fail() {
+        let matrix = SquareMatrix::<Rational<i64>>::from_str("{{1,2,3},{4,5,6},{7,8,9}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.inverse_gauss_jordan(0.),
+            Err(MatrixError::SingularMatrix)
+        );
+        let matrix = SquareMatrix::<Real>::from_str("{{1,2},{2,4.0000001}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.inverse_gauss_jordan(1e-5),
+            Err(MatrixError::SingularMatrix)
+        );
     }
 }
@@ -0,0 +1,337 @@
+use crate::{
+    equality::Tolerance,
+    matrix::{error::MatrixError, AsMatrix},
+    structures::Field,
+};
+
+use super::{determinant::Signature, SquareMatrix};
+
+/// [LU decomposition](https://en.wikipedia.org/wiki/LU_decomposition) with partial pivoting of a
+/// [`SquareMatrix`], i.e., the factorization _PA = LU_ where:
+/// - _P_ is a permutation matrix, stored as the vector of row indexes of _A_.
+/// - _L_ is a lower triangular matrix with ones in the diagonal.
+/// - _U_ is an upper triangular matrix.
+///
+/// Once computed, it can be reused to solve many systems with the same coefficient matrix,
+/// each one with a cost of _O(n^2)_ instead of the _O(n^3)_ of a new elimination.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuDecomposition<R>
+where
+    R: Field,
+{
+    lower: SquareMatrix<R>,
+    upper: SquareMatrix<R>,
+    permutation: Vec<usize>,
+    signature: Signature,
+    singular: bool,
+}
+
+impl<R> LuDecomposition<R>
+where
+    R: Field + PartialOrd,
+{
+    /// Computes the decomposition of the given `matrix`.
+    ///
+    /// ## Parameters
+    /// - `tolerance`: The tolerance used to determine if a pivot is zero.
+    ///
+    /// ## Example
+    ///
+    /// If you have the matrix
+    /// ```txt
+    ///     0   1
+    /// A = 2   4
+    /// ```
+    /// then the rows are swapped and we get
+    /// ```txt
+    ///     1   0        2   4
+    /// L = 0   1    U = 0   1    P = [1, 0]
+    /// ```
+    ///
+    /// Singular matrices can also be decomposed, but then [`LuDecomposition::solve`] and
+    /// [`LuDecomposition::inverse`] will fail.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn new<T: Tolerance>(matrix: &SquareMatrix<R>, tolerance: T) -> Result<Self, MatrixError> {
+        let dimension = matrix.dimension();
+        let mut upper = matrix.clone();
+        let mut lower = SquareMatrix::<R>::identity(dimension);
+        let mut permutation = (0..dimension).collect::<Vec<usize>>();
+        let mut signature = Signature::Even;
+        let mut singular = false;
+
+        for column in 0..dimension {
+            let mut pivot_row = column;
+            for row in column + 1..dimension {
+                if upper[(row, column)].abs_value() > upper[(pivot_row, column)].abs_value() {
+                    pivot_row = row;
+                }
+            }
+            if upper[(pivot_row, column)].is_zero(tolerance) {
+                // The pivot is the largest element, so the rest of the column is negligible too
+                // and zeroing it keeps U upper triangular.
+                singular = true;
+                for row in column + 1..dimension {
+                    upper[(row, column)] = R::zero();
+                }
+                continue;
+            }
+            if pivot_row != column {
+                upper.swap_rows(column, pivot_row)?;
+                permutation.swap(column, pivot_row);
+                signature.change();
+                for j in 0..column {
+                    let element = lower[(column, j)].to_owned();
+                    lower[(column, j)] = lower[(pivot_row, j)].to_owned();
+                    lower[(pivot_row, j)] = element;
+                }
+            }
+            for row in column + 1..dimension {
+                let factor = upper[(row, column)].to_owned() / upper[(column, column)].to_owned();
+                lower[(row, column)] = factor.to_owned();
+                upper[(row, column)] = R::zero();
+                for j in column + 1..dimension {
+                    let new_value = upper[(row, j)].to_owned()
+                        - factor.to_owned() * upper[(column, j)].to_owned();
+                    upper[(row, j)] = new_value;
+                }
+            }
+        }
+
+        Ok(Self {
+            lower,
+            upper,
+            permutation,
+            signature,
+            singular,
+        })
+    }
+
+    /// Returns the lower triangular factor _L_.
+    pub fn lower(&self) -> &SquareMatrix<R> {
+        &self.lower
+    }
+
+    /// Returns the upper triangular factor _U_.
+    pub fn upper(&self) -> &SquareMatrix<R> {
+        &self.upper
+    }
+
+    /// Returns the permutation, where the element `i` is the row of the original matrix that
+    /// has been moved to the row `i`.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the [`Signature`] of the permutation.
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    /// Returns whether the decomposed matrix is singular.
+    pub fn is_singular(&self) -> bool {
+        self.singular
+    }
+
+    /// Returns the determinant of the decomposed matrix, i.e., the product of the diagonal of
+    /// _U_ with the sign of the permutation.
+    pub fn determinant(&self) -> R {
+        if self.singular {
+            return R::zero();
+        }
+        let mut determinant = self.signature.as_number::<R>();
+        for i in 0..self.upper.dimension() {
+            determinant = determinant * self.upper[(i, i)].to_owned();
+        }
+        determinant
+    }
+
+    /// Solves the system _Ax = b_ by forward and backward substitution.
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::InvalidNumberOfRows`] if `b` has not the dimension of the matrix.
+    /// - [`MatrixError::SingularMatrix`] if the decomposed matrix is singular.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^2)_.
+    pub fn solve(&self, b: &[R]) -> Result<Vec<R>, MatrixError> {
+        let dimension = self.upper.dimension();
+        if b.len() != dimension {
+            return Err(MatrixError::InvalidNumberOfRows);
+        }
+        if self.singular {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        let mut y: Vec<R> = Vec::with_capacity(dimension);
+        for i in 0..dimension {
+            let mut value = b[self.permutation[i]].to_owned();
+            for (j, y_j) in y.iter().enumerate() {
+                value = value - self.lower[(i, j)].to_owned() * y_j.to_owned();
+            }
+            y.push(value);
+        }
+
+        let mut x = vec![R::zero(); dimension];
+        for i in (0..dimension).rev() {
+            let mut value = y[i].to_owned();
+            for (j, x_j) in x.iter().enumerate().skip(i + 1) {
+                value = value - self.upper[(i, j)].to_owned() * x_j.to_owned();
+            }
+            x[i] = value / self.upper[(i, i)].to_owned();
+        }
+        Ok(x)
+    }
+
+    /// Returns the inverse of the decomposed matrix, solving the system for every column of the
+    /// identity.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`MatrixError::SingularMatrix`] if the decomposed matrix is singular.
+    ///
+    /// ## Complexity
+    /// The complexity of this algorithm is _O(n^3)_.
+    pub fn inverse(&self) -> Result<SquareMatrix<R>, MatrixError> {
+        let dimension = self.upper.dimension();
+        let mut inverse = SquareMatrix::<R>::identity(dimension);
+        for column in 0..dimension {
+            let mut unit = vec![R::zero(); dimension];
+            unit[column] = R::one();
+            for (row, value) in self.solve(&unit)?.into_iter().enumerate() {
+                inverse[(row, column)] = value;
+            }
+        }
+        Ok(inverse)
+    }
+}
+
+impl<R: Field + PartialOrd> SquareMatrix<R> {
+    /// Returns the [`LuDecomposition`] of the matrix. See [`LuDecomposition::new`].
+    pub fn lu_decomposition<T: Tolerance>(
+        &self,
+        tolerance: T,
+    ) -> Result<LuDecomposition<R>, MatrixError> {
+        LuDecomposition::new(self, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{
+            square::{determinant::Signature, SquareMatrix},
+            MatrixError,
+        },
+        structures::{integers::Integer, rationals::Rational, reals::Real},
+    };
+
+    fn rational(numerator: i64, denominator: i64) -> Rational<i64> {
+        Rational::new(Integer::new(numerator), Integer::new(denominator))
+    }
+
+    #[test]
+    fn lu_decomposition_should_rebuild_the_matrix() {
+        let matrix = SquareMatrix::<Rational<i64>>::from_str("{{1,2,3},{4,5,6},{7,8,10}}").unwrap();
+        let lu = matrix.lu_decomposition(0.).unwrap();
+        let mut permuted = matrix.clone();
+        for (row, original_row) in lu.permutation().iter().enumerate() {
+            for column in 0..matrix.dimension() {
+                permuted[(row, column)] = matrix[(*original_row, column)];
+            }
+        }
+        let product = (lu.lower().clone() * lu.upper().clone()).unwrap();
+        assert!(product.equals(&permuted, 0.));
+        for i in 0..matrix.dimension() {
+            assert!(lu.lower()[(i, i)].equals(&rational(1, 1), 0.));
+            for j in i + 1..matrix.dimension() {
+                assert!(lu.lower()[(i, j)].equals(&rational(0, 1), 0.));
+                assert!(lu.upper()[(j, i)].equals(&rational(0, 1), 0.));
+            }
+        }
+    }
+
+    #[test]
+    fn lu_determinant_should_take_the_permutation_sign() {
+        let matrix = SquareMatrix::<Rational<i64>>::from_str("{{0,1},{1,0}}").unwrap();
+        let lu = matrix.lu_decomposition(0.).unwrap();
+        pretty_assertions::assert_eq!(lu.signature(), Signature::Odd);
+        assert!(lu.determinant().equals(&rational(-1, 1), 0.));
+
+        let matrix = SquareMatrix::<Rational<i64>>::from_str("{{1,2,3},{4,5,6},{7,8,10}}").unwrap();
+        assert!(matrix
+            .lu_decomposition(0.)
+            .unwrap()
+            .determinant()
+            .equals(&rational(-3, 1), 0.));
+
+        let matrix = SquareMatrix::<Real>::from_str("{{1,2,3},{4,5,6},{7,8,9}}").unwrap();
+        let lu = matrix.lu_decomposition(1e-5).unwrap();
+        assert!(lu.is_singular());
+        assert!(lu.determinant().equals(&Real::new(0.), 1e-5));
+    }
+
+    #[test]
+    fn lu_decomposition_of_singular_matrix_should_be_triangular() {
+        let matrix = SquareMatrix::<Real>::from_str("{{1e-8,1,2},{2e-8,3,4},{0,5,7}}").unwrap();
+        let lu = matrix.lu_decomposition(1e-6).unwrap();
+        assert!(lu.is_singular());
+        for i in 0..matrix.dimension() {
+            for j in 0..i {
+                pretty_assertions::assert_eq!(lu.upper()[(i, j)], Real::new(0.));
+            }
+        }
+        let mut permuted = matrix.clone();
+        for (row, original_row) in lu.permutation().iter().enumerate() {
+            for column in 0..matrix.dimension() {
+                permuted[(row, column)] = matrix[(*original_row, column)];
+            }
+        }
+        let product = (lu.lower().clone() * lu.upper().clone()).unwrap();
+        assert!(product.equals(&permuted, 1e-5));
+        pretty_assertions::assert_eq!(lu.determinant(), Real::new(0.));
+    }
+
+    #[test]
+    fn lu_solve_should_work_for_many_right_hand_sides() {
+        let matrix =
+            SquareMatrix::<Rational<i64>>::from_str("{{2,1,1},{4,-6,0},{-2,7,2}}").unwrap();
+        let lu = matrix.lu_decomposition(0.).unwrap();
+        vec![
+            (vec![5, -2, 9], vec![1, 1, 2]),
+            (vec![2, 4, -2], vec![1, 0, 0]),
+            (vec![0, 0, 0], vec![0, 0, 0]),
+        ]
+        .into_iter()
+        .for_each(|(b, expected)| {
+            let b = b.into_iter().map(Rational::from).collect::<Vec<_>>();
+            let x = lu.solve(&b).unwrap();
+            x.iter().zip(expected).for_each(|(computed, expected)| {
+                assert!(computed.equals(&rational(expected, 1), 0.))
+            });
+        });
+        pretty_assertions::assert_eq!(
+            lu.solve(&[Rational::from(1)]),
+            Err(MatrixError::InvalidNumberOfRows)
+        );
+    }
+
+    #[test]
+    fn lu_inverse_should_match_gauss_jordan() {
+        let matrix = SquareMatrix::<Real>::from_str("{{4,3,2},{2,1,3},{3,2,1}}").unwrap();
+        let lu_inverse = matrix.lu_decomposition(1e-6).unwrap().inverse().unwrap();
+        let gauss_jordan_inverse = matrix.inverse_gauss_jordan(1e-6).unwrap();
+        assert!(lu_inverse.equals(&gauss_jordan_inverse, 1e-5));
+
+        let singular = SquareMatrix::<Real>::from_str("{{1,2},{2,4}}").unwrap();
+        pretty_assertions::assert_eq!(
+            singular.lu_decomposition(1e-6).unwrap().inverse(),
+            Err(MatrixError::SingularMatrix)
+        );
+    }
+}
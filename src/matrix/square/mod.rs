@@ -1,12 +1,18 @@
+pub mod characteristic;
+pub mod cholesky;
 pub mod determinant;
+pub mod eigen;
 pub mod equality;
+pub mod inverse;
+pub mod lu;
+pub mod ops;
 pub mod parser;
 
 use std::ops::{Index, IndexMut};
 
-use crate::structures::Ring;
+use crate::{equality::Tolerance, structures::Ring};
 
-use super::{error::MatrixError, AsMatrix};
+use super::{error::MatrixError, generic::Matrix, AsMatrix};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct SquareMatrix<R>
@@ -33,6 +39,7 @@ where
     /// Given the parameters
     /// * `dimension = 3`,
     /// * `fn f(i: usize, j: usize) { i + j }`
+    ///
     /// we would get the matrix
     /// ```txt
     /// 0 1 2
@@ -49,12 +56,30 @@ where
         Self::new(dimension, data)
     }
 
+    /// Creates the identity matrix of the given `dimension`.
+    ///
+    /// ## Example
+    ///
+    /// Given `dimension = 3`, we would get the matrix
+    /// ```txt
+    /// 1 0 0
+    /// 0 1 0
+    /// 0 0 1
+    /// ```
+    pub fn identity(dimension: usize) -> Self {
+        let mut data = vec![vec![R::zero(); dimension]; dimension];
+        for (i, row) in data.iter_mut().enumerate() {
+            row[i] = R::one();
+        }
+        Self::new(dimension, data)
+    }
+
     pub fn dimension(&self) -> usize {
         self.dimension
     }
 
     /// Checks if any element of the diagonal is zero
-    pub fn diagonal_is_zero(&self, tolerance: f32) -> bool {
+    pub fn diagonal_is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
         for row in 0..self.dimension() {
             if self.data[row][row].is_zero(tolerance) {
                 return true;
@@ -76,6 +101,7 @@ where
     /// and the parameters
     /// * `row = 1`
     /// * `column = 2`
+    ///
     /// we would get the matrix
     /// ```txt
     /// 1 2
@@ -137,7 +163,10 @@ where
     ///
     /// ## Time complexity
     /// This function has a time complexity of `O(n^2)`.
-    pub fn swap_rows_with_0_pivot(&mut self, tolerance: f32) -> Result<bool, MatrixError> {
+    pub fn swap_rows_with_0_pivot<T: Tolerance>(
+        &mut self,
+        tolerance: T,
+    ) -> Result<bool, MatrixError> {
         for row in 0..self.dimension() {
             if self.data[row][row].is_zero(tolerance) {
                 for row2 in row + 1..self.dimension() {
@@ -170,6 +199,20 @@ impl<R: Ring> TryFrom<Vec<Vec<R>>> for SquareMatrix<R> {
     }
 }
 
+impl<R: Ring> TryFrom<Matrix<R>> for SquareMatrix<R> {
+    type Error = MatrixError;
+
+    fn try_from(value: Matrix<R>) -> Result<Self, Self::Error> {
+        Self::try_from(value.data)
+    }
+}
+
+impl<R: Ring> From<SquareMatrix<R>> for Matrix<R> {
+    fn from(value: SquareMatrix<R>) -> Self {
+        Matrix { data: value.data }
+    }
+}
+
 impl<R: Ring> Default for SquareMatrix<R> {
     fn default() -> Self {
         Self {
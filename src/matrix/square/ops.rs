@@ -0,0 +1,66 @@
+use std::ops::Mul;
+
+use crate::{
+    matrix::{generic::ops::dot_product, MatrixError},
+    structures::Ring,
+};
+
+use super::SquareMatrix;
+
+/// The factors of every product keep their order, _a<sub>ik</sub> · b<sub>kj</sub>_, so it works for
+/// non-commutative rings, like the [`Quaternion`](crate::structures::quaternions::Quaternion)s.
+///
+/// Returns [`MatrixError::Overflow`] if some product or sum cannot be represented, see
+/// [`Ring::checked_mul`].
+impl<R: Ring + PartialOrd> Mul for SquareMatrix<R> {
+    type Output = Result<Self, MatrixError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.dimension() != rhs.dimension() {
+            return Err(MatrixError::InvalidDimension(rhs.dimension()));
+        }
+        let dimension = self.dimension();
+        let mut result = SquareMatrix::new(dimension, vec![vec![R::zero(); dimension]; dimension]);
+        for row in 0..dimension {
+            for column in 0..dimension {
+                result[(row, column)] = dot_product(
+                    (0..dimension).map(|i| &self[(row, i)]),
+                    (0..dimension).map(|i| &rhs[(i, column)]),
+                )?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        equality::Equals,
+        matrix::{square::SquareMatrix, MatrixError},
+        structures::integers::Integer,
+    };
+
+    #[test]
+    fn multiplication_should_not_fail() {
+        let matrix_a = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::new(1), Integer::new(2)],
+            vec![Integer::new(3), Integer::new(4)],
+        ])
+        .unwrap();
+        let expected = SquareMatrix::<Integer<i32>>::try_from(vec![
+            vec![Integer::new(7), Integer::new(10)],
+            vec![Integer::new(15), Integer::new(22)],
+        ])
+        .unwrap();
+        let computed = (matrix_a.clone() * matrix_a).unwrap();
+        assert!(computed.equals(&expected, 0.));
+    }
+
+    #[test]
+    fn multiplication_with_different_dimensions_should_fail() {
+        let matrix_a = SquareMatrix::<Integer<i32>>::identity(2);
+        let matrix_b = SquareMatrix::<Integer<i32>>::identity(3);
+        pretty_assertions::assert_eq!(matrix_a * matrix_b, Err(MatrixError::InvalidDimension(3)));
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt::Display;
+
+use crate::structures::{IntegralDomain, Ring};
+
+use super::{square::determinant::Signature, AsMatrix, MatrixError};
+
+/// Elementary row operation applied to a matrix during an elimination.
+///
+/// Rows are indexed from `0`, but they are displayed from `1`, as they are usually written
+/// by hand (e.g. `R1 <-> R2`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowOperation<R: Ring> {
+    /// Swaps the rows `first` and `second`.
+    Swap { first: usize, second: usize },
+    /// Multiplies every element of `row` by `factor`.
+    Scale { row: usize, factor: R },
+    /// Adds `factor` times the row `source` to the row `target`.
+    AddMultiple {
+        factor: R,
+        source: usize,
+        target: usize,
+    },
+    /// Divides every element of `row` by `divisor`. It is only used by fraction-free methods,
+    /// like the Bareiss algorithm, where the division is known to be exact.
+    Divide { row: usize, divisor: R },
+}
+
+impl<R: IntegralDomain + PartialOrd> RowOperation<R> {
+    /// Applies the operation to the given `matrix`.
+    ///
+    /// ## Errors
+    ///
+    /// - [`MatrixError::RowOutOfBounds`] if some of the rows are out of bounds.
+    /// - [`MatrixError::Overflow`] if some element cannot be represented.
+    pub fn apply<M: AsMatrix<R>>(&self, matrix: &mut M) -> Result<(), MatrixError> {
+        let check = |row: usize| {
+            if row >= matrix.rows() {
+                return Err(MatrixError::RowOutOfBounds(row));
+            }
+            Ok(())
+        };
+        match self {
+            RowOperation::Swap { first, second } => {
+                check(*first)?;
+                check(*second)?;
+                matrix.swap_rows(*first, *second)
+            }
+            RowOperation::Scale { row, factor } => {
+                check(*row)?;
+                for element in matrix.data_mut()[*row].iter_mut() {
+                    *element = element.checked_mul(factor).ok_or(MatrixError::Overflow)?;
+                }
+                Ok(())
+            }
+            RowOperation::AddMultiple {
+                factor,
+                source,
+                target,
+            } => {
+                check(*source)?;
+                check(*target)?;
+                for column in 0..matrix.columns() {
+                    let new_value = factor
+                        .checked_mul(&matrix.data()[*source][column])
+                        .and_then(|product| matrix.data()[*target][column].checked_add(&product))
+                        .ok_or(MatrixError::Overflow)?;
+                    matrix.data_mut()[*target][column] = new_value;
+                }
+                Ok(())
+            }
+            RowOperation::Divide { row, divisor } => {
+                check(*row)?;
+                for element in matrix.data_mut()[*row].iter_mut() {
+                    *element = element.checked_div(divisor).ok_or(MatrixError::Overflow)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<R: Ring> Display for RowOperation<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowOperation::Swap { first, second } => {
+                write!(f, "R{} <-> R{}", first + 1, second + 1)
+            }
+            RowOperation::Scale { row, factor } => {
+                write!(f, "R{} -> ({}) * R{}", row + 1, factor, row + 1)
+            }
+            RowOperation::AddMultiple {
+                factor,
+                source,
+                target,
+            } => write!(
+                f,
+                "R{} -> R{} + ({}) * R{}",
+                target + 1,
+                target + 1,
+                factor,
+                source + 1
+            ),
+            RowOperation::Divide { row, divisor } => {
+                write!(f, "R{} -> R{} / ({})", row + 1, row + 1, divisor)
+            }
+        }
+    }
+}
+
+/// A step of an [`EliminationTrace`]: the operation performed and the matrix obtained
+/// after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationStep<R: Ring, M> {
+    operation: RowOperation<R>,
+    matrix: M,
+}
+
+impl<R: Ring, M> EliminationStep<R, M> {
+    /// Returns the operation performed in this step.
+    pub fn operation(&self) -> &RowOperation<R> {
+        &self.operation
+    }
+
+    /// Returns the matrix obtained after performing the operation.
+    pub fn matrix(&self) -> &M {
+        &self.matrix
+    }
+}
+
+/// Record of every elementary row operation performed over a matrix, together with every
+/// intermediate matrix.
+///
+/// It can be replayed from the initial matrix with [`EliminationTrace::replay`], and it is
+/// rendered with [`Display`] as the initial matrix followed by each operation and the matrix
+/// that it yields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EliminationTrace<R: Ring, M> {
+    initial: M,
+    steps: Vec<EliminationStep<R, M>>,
+}
+
+impl<R, M> EliminationTrace<R, M>
+where
+    R: IntegralDomain + PartialOrd,
+    M: AsMatrix<R>,
+{
+    /// Creates a trace with no steps.
+    pub fn new(initial: M) -> Self {
+        Self {
+            initial,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Applies `operation` to the current result and records it as a new step.
+    pub fn push(&mut self, operation: RowOperation<R>) -> Result<(), MatrixError> {
+        let mut matrix = self.result().clone();
+        operation.apply(&mut matrix)?;
+        self.steps.push(EliminationStep { operation, matrix });
+        Ok(())
+    }
+
+    /// Returns the matrix the trace starts from.
+    pub fn initial(&self) -> &M {
+        &self.initial
+    }
+
+    /// Returns the recorded steps.
+    pub fn steps(&self) -> &[EliminationStep<R, M>] {
+        &self.steps
+    }
+
+    /// Returns an iterator over the recorded operations.
+    pub fn operations(&self) -> impl Iterator<Item = &RowOperation<R>> {
+        self.steps.iter().map(|step| &step.operation)
+    }
+
+    /// Returns the matrix obtained after the last step, or the initial matrix if there are
+    /// no steps.
+    pub fn result(&self) -> &M {
+        self.steps.last().map_or(&self.initial, |step| &step.matrix)
+    }
+
+    /// Returns the number of row swaps performed, which determines the sign of a determinant.
+    pub fn swaps(&self) -> usize {
+        self.operations()
+            .filter(|operation| matches!(operation, RowOperation::Swap { .. }))
+            .count()
+    }
+
+    /// Returns the [`Signature`] of the row permutation performed by the swaps.
+    pub fn signature(&self) -> Signature {
+        let mut signature = Signature::Even;
+        for _ in 0..self.swaps() {
+            signature.change();
+        }
+        signature
+    }
+
+    /// Applies again every recorded operation to the initial matrix and returns the result,
+    /// which is the same as [`EliminationTrace::result`].
+    pub fn replay(&self) -> Result<M, MatrixError> {
+        let mut matrix = self.initial.clone();
+        for operation in self.operations() {
+            operation.apply(&mut matrix)?;
+        }
+        Ok(matrix)
+    }
+}
+
+impl<R, M> Display for EliminationTrace<R, M>
+where
+    R: Ring,
+    M: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.initial)?;
+        for step in self.steps.iter() {
+            write!(f, "\n{}\n{}", step.operation, step.matrix)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        matrix::{
+            generic::Matrix,
+            trace::{EliminationTrace, RowOperation},
+            AsMatrix, MatrixError,
+        },
+        structures::{rationals::Rational, reals::Real},
+    };
+
+    #[test]
+    fn apply_row_operations_should_not_fail() {
+        let mut matrix = Matrix::<Rational<i32>>::from_str("{{1,2},{3,4}}").unwrap();
+        RowOperation::Swap {
+            first: 0,
+            second: 1,
+        }
+        .apply(&mut matrix)
+        .unwrap();
+        RowOperation::Scale {
+            row: 1,
+            factor: Rational::from(2),
+        }
+        .apply(&mut matrix)
+        .unwrap();
+        RowOperation::AddMultiple {
+            factor: Rational::from(-1),
+            source: 1,
+            target: 0,
+        }
+        .apply(&mut matrix)
+        .unwrap();
+        RowOperation::Divide {
+            row: 1,
+            divisor: Rational::from(2),
+        }
+        .apply(&mut matrix)
+        .unwrap();
+        let expected = Matrix::<Rational<i32>>::from_str("{{1,0},{1,2}}").unwrap();
+        assert!(matrix.equals(&expected, 0.));
+
+        pretty_assertions::assert_eq!(
+            RowOperation::Swap {
+                first: 0,
+                second: 2
+            }
+            .apply(&mut matrix),
+            Err(MatrixError::RowOutOfBounds(2))
+        );
+    }
+
+    #[test]
+    fn traced_gaussian_elimination_should_replay() {
+        let matrix = Matrix::<Rational<i32>>::from_str("{{1,2,3},{4,5,6},{7,8,10}}").unwrap();
+        let trace = matrix.gaussian_elimination_traced(0.).unwrap();
+        let replayed = trace.replay().unwrap();
+        assert!(replayed.equals(trace.result(), 0.));
+        assert!(trace
+            .result()
+            .equals(&matrix.gaussian_elimination(0.).unwrap(), 0.));
+        assert!(trace.initial().equals(&matrix, 0.));
+        pretty_assertions::assert_eq!(trace.swaps(), 2);
+        pretty_assertions::assert_eq!(
+            trace.operations().next(),
+            Some(&RowOperation::Swap {
+                first: 0,
+                second: 2
+            })
+        );
+
+        let matrix = Matrix::<Real>::from_str("{{1,2,3},{4,5,6},{7,8,9},{10,11,12}}").unwrap();
+        let trace = matrix.gaussian_elimination_traced(1e-6).unwrap();
+        pretty_assertions::assert_eq!(&trace.replay().unwrap(), trace.result());
+        assert!(trace
+            .result()
+            .equals(&matrix.gaussian_elimination(1e-6).unwrap(), 1e-5));
+    }
+
+    #[test]
+    fn display_trace() {
+        let matrix = Matrix::<Rational<i32>>::from_str("{{0,1},{2,4}}").unwrap();
+        let mut trace = EliminationTrace::new(matrix);
+        trace
+            .push(RowOperation::Swap {
+                first: 0,
+                second: 1,
+            })
+            .unwrap();
+        trace
+            .push(RowOperation::Scale {
+                row: 0,
+                factor: Rational::from_str("1/2").unwrap(),
+            })
+            .unwrap();
+        pretty_assertions::assert_eq!(
+            trace.to_string(),
+            "0/1 1/1 \n2/1 4/1 \n\nR1 <-> R2\n2/1 4/1 \n0/1 1/1 \n\nR1 -> (1/2) * R1\n1/1 2/1 \n0/1 1/1 \n"
+        );
+    }
+}
@@ -36,3 +36,44 @@ macro_rules! impl_from_f32 {
 }
 
 impl_from_f32!(isize, i8, i16, i32, i64, i128);
+
+/// Helper trait to convert any value to [`f64`] type.
+///
+/// It is the double precision counterpart of [`AsF32`], for the types that keep more digits
+/// than [`f32`], as [`Real64`](crate::structures::reals::Real64).
+pub trait AsF64 {
+    /// This function is the equivalent to the primitive cast `as f64`.
+    fn as_f64(&self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($t:ty),*) => {
+        $(impl AsF64 for $t {
+            fn as_f64(&self) -> f64 {
+                *self as f64
+            }
+        })*
+    };
+}
+
+impl_as_f64!(isize, i8, i16, i32, i64, i128, f32, f64);
+
+/// Helper trait to obtain any value from [`f64`] type.
+///
+/// It is the double precision counterpart of [`FromF32`].
+pub trait FromF64 {
+    /// For primitive types, this function is the equivalent to the primitive cast `as`.
+    fn from_f64(value: f64, tolerance: f64) -> Self;
+}
+
+macro_rules! impl_from_f64 {
+    ($($t:ty),*) => {
+        $(impl FromF64 for $t {
+            fn from_f64(value: f64, _: f64) -> Self {
+                value as $t
+            }
+        })*
+    };
+}
+
+impl_from_f64!(isize, i8, i16, i32, i64, i128, f32, f64);
@@ -0,0 +1,698 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use super::{
+    errors::StructureError, CheckedRing, CommutativeRing, EuclideanDomain, Group, IntegralDomain,
+    Ring,
+};
+use crate::{
+    equality::{Equals, Tolerance},
+    identities::{One, Zero},
+    num_types::{AsF32, FromF32},
+    traits::Abs,
+};
+
+/// Base of the limbs of a [`BigInt`].
+const BASE: u64 = 1 << 32;
+
+/// Greatest power of ten that fits in a limb, used to convert from and to decimal strings.
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+/// Arbitrary-precision integer number.
+///
+/// It is stored as a sign and a magnitude of 32-bit limbs, from the least significant to the
+/// most significant one, without trailing zero limbs. Zero has an empty magnitude and is never
+/// negative.
+///
+/// As the primitive integers, the division truncates towards zero and the remainder has the
+/// sign of the dividend. Hence, it can be used as the inner type of
+/// [`Integer`](super::integers::Integer) and [`Rational`](super::rationals::Rational) to
+/// operate without overflows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+        Self {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    /// Returns whether the number is strictly negative.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the number of bits needed to represent the absolute value of the number.
+    pub fn bits(&self) -> usize {
+        match self.magnitude.last() {
+            Some(last) => 32 * self.magnitude.len() - last.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the quotient and the remainder of the division, the first one truncated
+    /// towards zero and the second one with the sign of `self`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero, as primitive integers do.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.magnitude.is_empty() {
+            panic!("attempt to divide by zero");
+        }
+        let (quotient, remainder) = div_rem_magnitude(&self.magnitude, &rhs.magnitude);
+        (
+            Self::from_parts(self.negative != rhs.negative, quotient),
+            Self::from_parts(self.negative, remainder),
+        )
+    }
+
+    /// Returns `self` raised to the power of `exponent`, by binary exponentiation.
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base.clone();
+            }
+            base = base.clone() * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+fn compare_magnitude(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+fn add_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (long, short) = if lhs.len() >= rhs.len() {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    let mut result = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0_u64;
+    for (i, limb) in long.iter().enumerate() {
+        let sum = *limb as u64 + *short.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+    result
+}
+
+/// Subtracts the magnitudes, where `lhs` must be greater or equal than `rhs`.
+fn sub_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(lhs.len());
+    let mut borrow = 0_i64;
+    for (i, limb) in lhs.iter().enumerate() {
+        let difference = *limb as i64 - *rhs.get(i).unwrap_or(&0) as i64 + borrow;
+        result.push(difference as u32);
+        borrow = difference >> 32;
+    }
+    result
+}
+
+fn mul_magnitude(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0_u32; lhs.len() + rhs.len()];
+    for (i, a) in lhs.iter().enumerate() {
+        let mut carry = 0_u64;
+        for (j, b) in rhs.iter().enumerate() {
+            let product = *a as u64 * *b as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + rhs.len()] = carry as u32;
+    }
+    result
+}
+
+/// Divides the magnitude by a single limb, returning the quotient and the remainder.
+fn div_rem_limb(lhs: &[u32], rhs: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0_u32; lhs.len()];
+    let mut remainder = 0_u64;
+    for (i, limb) in lhs.iter().enumerate().rev() {
+        let current = (remainder << 32) | *limb as u64;
+        quotient[i] = (current / rhs as u64) as u32;
+        remainder = current % rhs as u64;
+    }
+    (quotient, remainder as u32)
+}
+
+fn shift_left(magnitude: &[u32], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(magnitude.len() + 1);
+    let mut carry = 0_u32;
+    for limb in magnitude {
+        if shift == 0 {
+            result.push(*limb);
+        } else {
+            result.push((limb << shift) | carry);
+            carry = limb >> (32 - shift);
+        }
+    }
+    result.push(carry);
+    result
+}
+
+fn shift_right(magnitude: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return magnitude.to_vec();
+    }
+    let mut result = vec![0_u32; magnitude.len()];
+    for i in 0..magnitude.len() {
+        let high = magnitude.get(i + 1).map_or(0, |limb| limb << (32 - shift));
+        result[i] = (magnitude[i] >> shift) | high;
+    }
+    result
+}
+
+/// Long division of magnitudes, following the algorithm D of Knuth's _The Art of Computer
+/// Programming_, Vol. 2, Section 4.3.1.
+fn div_rem_magnitude(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_magnitude(lhs, rhs) == Ordering::Less {
+        return (Vec::new(), lhs.to_vec());
+    }
+    if rhs.len() == 1 {
+        let (quotient, remainder) = div_rem_limb(lhs, rhs[0]);
+        return (quotient, vec![remainder]);
+    }
+
+    // Normalize so that the most significant limb of the divisor has its highest bit set.
+    let shift = rhs[rhs.len() - 1].leading_zeros();
+    let divisor = shift_left(rhs, shift);
+    let divisor = &divisor[..rhs.len()];
+    let mut dividend = shift_left(lhs, shift);
+    let n = divisor.len();
+    let m = lhs.len() - n;
+    let mut quotient = vec![0_u32; m + 1];
+
+    for j in (0..=m).rev() {
+        let numerator = ((dividend[j + n] as u64) << 32) | dividend[j + n - 1] as u64;
+        let mut q_hat = numerator / divisor[n - 1] as u64;
+        let mut r_hat = numerator % divisor[n - 1] as u64;
+        while q_hat >= BASE
+            || q_hat * divisor[n - 2] as u64 > ((r_hat << 32) | dividend[j + n - 2] as u64)
+        {
+            q_hat -= 1;
+            r_hat += divisor[n - 1] as u64;
+            if r_hat >= BASE {
+                break;
+            }
+        }
+
+        let mut borrow = 0_i64;
+        let mut carry = 0_u64;
+        for i in 0..n {
+            let product = q_hat * divisor[i] as u64 + carry;
+            carry = product >> 32;
+            let difference = dividend[i + j] as i64 - (product & 0xffff_ffff) as i64 + borrow;
+            dividend[i + j] = difference as u32;
+            borrow = difference >> 32;
+        }
+        let difference = dividend[j + n] as i64 - carry as i64 + borrow;
+        dividend[j + n] = difference as u32;
+
+        if difference < 0 {
+            // The estimation was one unit too big, so the divisor is added back.
+            q_hat -= 1;
+            let mut carry = 0_u64;
+            for i in 0..n {
+                let sum = dividend[i + j] as u64 + divisor[i] as u64 + carry;
+                dividend[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+            dividend[j + n] = dividend[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = q_hat as u32;
+    }
+
+    (quotient, shift_right(&dividend[..n], shift))
+}
+
+macro_rules! impl_big_int_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for BigInt {
+            fn from(value: $t) -> Self {
+                let negative = value < 0;
+                let mut magnitude = (value as i128).unsigned_abs();
+                let mut limbs = Vec::new();
+                while magnitude > 0 {
+                    limbs.push(magnitude as u32);
+                    magnitude >>= 32;
+                }
+                Self::from_parts(negative, limbs)
+            }
+        })*
+    };
+}
+
+impl_big_int_from_signed!(isize, i8, i16, i32, i64, i128);
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.magnitude.is_empty() {
+            return write!(f, "0");
+        }
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, remainder) = div_rem_limb(&magnitude, DECIMAL_BASE);
+            chunks.push(remainder);
+            magnitude = BigInt::from_parts(false, quotient).magnitude;
+        }
+        let mut result = String::new();
+        if self.negative {
+            result.push('-');
+        }
+        let mut chunks = chunks.iter().rev();
+        if let Some(first) = chunks.next() {
+            result.push_str(&first.to_string());
+        }
+        for chunk in chunks {
+            result.push_str(&format!("{:0width$}", chunk, width = DECIMAL_DIGITS));
+        }
+        write!(f, "{}", result)
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = StructureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(StructureError::ParseError(format!(
+                "Invalid big integer: {}",
+                s
+            )));
+        }
+        let first_chunk = match digits.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            length => length,
+        };
+        let mut magnitude = Vec::new();
+        let mut start = 0;
+        let mut end = first_chunk;
+        while start < digits.len() {
+            let chunk = digits[start..end].parse::<u32>()?;
+            let scale = 10_u32.pow((end - start) as u32);
+            magnitude = add_magnitude(&mul_magnitude(&magnitude, &[scale]), &[chunk]);
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+        Ok(Self::from_parts(negative, magnitude))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigInt {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.negative == rhs.negative {
+            return Self::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &rhs.magnitude),
+            );
+        }
+        match compare_magnitude(&self.magnitude, &rhs.magnitude) {
+            Ordering::Less => {
+                Self::from_parts(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+            }
+            _ => Self::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &rhs.magnitude),
+            ),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Sub for BigInt {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul for BigInt {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_parts(
+            self.negative != rhs.negative,
+            mul_magnitude(&self.magnitude, &rhs.magnitude),
+        )
+    }
+}
+
+impl Div for BigInt {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl Rem for BigInt {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl Zero for BigInt {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn is_zero<T: Tolerance>(&self, _: T) -> bool {
+        self.magnitude.is_empty()
+    }
+}
+
+impl One for BigInt {
+    fn one() -> Self {
+        Self::from_parts(false, vec![1])
+    }
+
+    fn is_one<T: Tolerance>(&self, _: T) -> bool {
+        !self.negative && self.magnitude == [1]
+    }
+}
+
+impl Equals for BigInt {
+    fn equals<T: Tolerance>(&self, rhs: &Self, _: T) -> bool {
+        self == rhs
+    }
+}
+
+impl Abs for BigInt {
+    type Output = Self;
+
+    fn abs_value(&self) -> Self::Output {
+        Self::from_parts(false, self.magnitude.clone())
+    }
+}
+
+impl AsF32 for BigInt {
+    /// Returns the closest [`f32`], which might be infinite for huge numbers.
+    fn as_f32(&self) -> f32 {
+        let value = self
+            .magnitude
+            .iter()
+            .rev()
+            .fold(0_f32, |value, limb| value * BASE as f32 + *limb as f32);
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+impl FromF32 for BigInt {
+    /// Truncates the value towards zero, as the primitive cast `as` does.
+    fn from_f32(value: f32, _: f32) -> Self {
+        let mut magnitude = value.abs() as u128;
+        let mut limbs = Vec::new();
+        while magnitude > 0 {
+            limbs.push(magnitude as u32);
+            magnitude >>= 32;
+        }
+        Self::from_parts(value < 0., limbs)
+    }
+}
+
+impl Group for BigInt {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        -self.clone()
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+}
+
+impl Ring for BigInt {
+    fn sum(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.clone() * rhs.clone()
+    }
+}
+
+impl CommutativeRing for BigInt {}
+
+impl IntegralDomain for BigInt {
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.magnitude.is_empty() {
+            return None;
+        }
+        Some(self.div_rem(rhs).0)
+    }
+}
+
+impl EuclideanDomain for BigInt {
+    type Norm = BigInt;
+
+    fn norm(&self) -> Self::Norm {
+        self.abs_value()
+    }
+
+    /// Returns the quotient and the remainder of the Euclidean division, where the remainder is
+    /// always non-negative, unlike [`BigInt::div_rem`].
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = BigInt::div_rem(self, rhs);
+        if !remainder.negative {
+            return (quotient, remainder);
+        }
+        if rhs.negative {
+            (quotient + Self::one(), remainder - rhs.clone())
+        } else {
+            (quotient - Self::one(), remainder + rhs.clone())
+        }
+    }
+}
+
+/// A [`BigInt`] never overflows, so the saturating operations are the plain ones.
+impl CheckedRing for BigInt {
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.clone() - rhs.clone()
+    }
+
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        self.clone() * rhs.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::BigInt;
+    use crate::{
+        arithmetics::euclid,
+        equality::Equals,
+        matrix::square::{determinant::DeterminantMethod, SquareMatrix},
+        num_types::AsF32,
+        structures::{integers::Integer, rationals::Rational},
+    };
+
+    #[test]
+    fn operations_should_match_primitives() {
+        let values: Vec<i128> = vec![
+            0,
+            1,
+            -1,
+            7,
+            -7,
+            4_294_967_295,
+            4_294_967_296,
+            -4_294_967_297,
+            123_456_789_012_345_678,
+            -98_765_432_109_876_543,
+            i64::MAX as i128,
+            i64::MIN as i128,
+        ];
+        for a in values.iter() {
+            for b in values.iter() {
+                let big_a = BigInt::from(*a);
+                let big_b = BigInt::from(*b);
+                pretty_assertions::assert_eq!(big_a.clone() + big_b.clone(), BigInt::from(a + b));
+                pretty_assertions::assert_eq!(big_a.clone() - big_b.clone(), BigInt::from(a - b));
+                pretty_assertions::assert_eq!(big_a.clone() * big_b.clone(), BigInt::from(a * b));
+                pretty_assertions::assert_eq!(big_a.cmp(&big_b), a.cmp(b));
+                if *b != 0 {
+                    pretty_assertions::assert_eq!(
+                        big_a.clone() / big_b.clone(),
+                        BigInt::from(a / b),
+                        "{a} / {b}"
+                    );
+                    pretty_assertions::assert_eq!(
+                        big_a.clone() % big_b.clone(),
+                        BigInt::from(a % b),
+                        "{a} % {b}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn long_division_should_not_fail() {
+        let a = BigInt::from_str("123456789012345678901234567890123456789012345678901234567890")
+            .unwrap();
+        let b = BigInt::from_str("-987654321098765432109876543210").unwrap();
+        let r = BigInt::from_str("-12345678901234567890").unwrap();
+        let n = a.clone() * b.clone() + r.clone();
+        pretty_assertions::assert_eq!(n.div_rem(&b), (a.clone(), r.clone()));
+        pretty_assertions::assert_eq!(n.div_rem(&a), (b, r));
+
+        let power = BigInt::from(2).pow(200);
+        let divisor = BigInt::from(2).pow(100) - BigInt::from(1);
+        let (quotient, remainder) = power.div_rem(&divisor);
+        pretty_assertions::assert_eq!(quotient * divisor + remainder, power);
+    }
+
+    #[test]
+    fn display_and_parse_should_round_trip() {
+        vec![
+            "0",
+            "-1",
+            "1000000000",
+            "999999999999999999",
+            "-30414093201713378043612608166064768844377641568960512000000000000",
+        ]
+        .into_iter()
+        .for_each(|input| {
+            pretty_assertions::assert_eq!(BigInt::from_str(input).unwrap().to_string(), input)
+        });
+        let factorial = (1..=50).fold(BigInt::from(1), |acc, i| acc * BigInt::from(i));
+        pretty_assertions::assert_eq!(
+            factorial.to_string(),
+            "30414093201713378043612608166064768844377641568960512000000000000"
+        );
+        assert!(BigInt::from_str("12a").is_err());
+        assert!(BigInt::from_str("").is_err());
+        assert!(BigInt::from_str("-").is_err());
+        pretty_assertions::assert_eq!(BigInt::from_str("+12").unwrap(), BigInt::from(12));
+        assert!((BigInt::from(-1_000_000).as_f32() + 1e6).abs() < 1e-3);
+    }
+
+    #[test]
+    fn big_integers_inside_integers_and_rationals() {
+        let a = Integer::new(BigInt::from(2).pow(100));
+        let b = Integer::new(BigInt::from(6).pow(50));
+        pretty_assertions::assert_eq!(euclid::gcd(&a, &b), Integer::new(BigInt::from(2).pow(50)));
+
+        let x = Rational::<BigInt>::from_str("1/3").unwrap();
+        let y = Rational::<BigInt>::from_str("-123456789012345678901234567890/7").unwrap();
+        let sum = x + y;
+        assert!(sum.equals(
+            &Rational::from_str("-370370367037037036703703703663/21").unwrap(),
+            0.
+        ));
+    }
+
+    /// Returns the element `(i, j)` of the product _LU_, where _L_ is a unit lower triangular
+    /// matrix and _U_ is an upper triangular matrix with `diagonal(k)` in its diagonal, both
+    /// with small elements elsewhere. Hence, the determinant is the product of the diagonal.
+    fn lu_product(i: usize, j: usize, diagonal: fn(usize) -> i64) -> BigInt {
+        let lower = |i: usize, k: usize| match i.cmp(&k) {
+            std::cmp::Ordering::Less => 0,
+            std::cmp::Ordering::Equal => 1,
+            std::cmp::Ordering::Greater => ((i * 3 + k * 5) % 3) as i64 - 1,
+        };
+        let upper = |k: usize, j: usize| match k.cmp(&j) {
+            std::cmp::Ordering::Less => ((k * 7 + j * 2) % 3) as i64 - 1,
+            std::cmp::Ordering::Equal => diagonal(k),
+            std::cmp::Ordering::Greater => 0,
+        };
+        BigInt::from(
+            (0..=i.min(j))
+                .map(|k| lower(i, k) * upper(k, j))
+                .sum::<i64>(),
+        )
+    }
+
+    #[test]
+    fn determinant_of_big_matrix_should_be_exact() {
+        let matrix =
+            SquareMatrix::from_fn(50, |i, j| Integer::new(lu_product(i, j, |k| k as i64 + 2)));
+        let expected = (2..=51).fold(BigInt::from(1), |acc, k| acc * BigInt::from(k));
+        let bareiss = matrix
+            .determinant(DeterminantMethod::BareissAlgorithm, 0.)
+            .unwrap();
+        pretty_assertions::assert_eq!(bareiss.value(), &expected);
+        assert!(expected.bits() > 128);
+    }
+
+    #[test]
+    fn inverse_of_big_matrix_should_be_exact() {
+        let matrix = SquareMatrix::from_fn(50, |i, j| Rational::from(lu_product(i, j, |_| 1)));
+        let inverse = matrix.inverse_gauss_jordan(0.).unwrap();
+        let identity = (matrix * inverse).unwrap();
+        assert!(identity.equals(&SquareMatrix::identity(50), 0.));
+    }
+}
@@ -1,254 +1,619 @@
 use std::{
-    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    ops::{Add, Div, Mul, Neg, Sub},
     str::FromStr,
 };
 
 use crate::{
-    equality::Equals,
+    equality::{Equals, Tolerance},
     identities::{One, Zero},
-    num_types::{AsF32, FromF32},
+    num_types::{AsF32, AsF64, FromF32},
     traits::Abs,
 };
 
-use super::{errors::StructureError, reals::Real, Field, Group, Ring};
+use super::{
+    errors::StructureError,
+    integers::Integer,
+    rationals::Rational,
+    reals::{Real, Real64},
+    CommutativeRing, EuclideanDomain, Field, Group, IntegralDomain, Ring,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Complex {
-    re: Real,
-    im: Real,
-}
+/// Defines a complex number type whose parts are of the given real type, with the arithmetic, the
+/// parsing and the algebraic structure that do not depend on the precision. The floating point
+/// type is the one of the parts, used for the polar form and the pairs of parts.
+macro_rules! complex_type {
+    ($(#[$attribute:meta])* $complex:ident, $real:ident, $float:ty) => {
+        $(#[$attribute])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $complex {
+            re: $real,
+            im: $real,
+        }
 
-impl Complex {
-    pub fn new(re: Real, im: Real) -> Self {
-        Self { re, im }
-    }
+        impl $complex {
+            pub fn new(re: $real, im: $real) -> Self {
+                Self { re, im }
+            }
 
-    pub fn conjugate(&self) -> Self {
-        Self {
-            re: self.re,
-            im: -self.im,
+            /// Returns the real part.
+            pub fn re(&self) -> $real {
+                self.re
+            }
+
+            /// Returns the imaginary part.
+            pub fn im(&self) -> $real {
+                self.im
+            }
+
+            pub fn conjugate(&self) -> Self {
+                Self {
+                    re: self.re,
+                    im: -self.im,
+                }
+            }
+
+            pub fn modulus(&self) -> $real {
+                (self.re * self.re + self.im * self.im).sqrt()
+            }
         }
-    }
 
-    pub fn modulus(&self) -> Real {
-        (self.re * self.re + self.im * self.im).sqrt()
-    }
-}
+        impl From<($float, $float)> for $complex {
+            fn from(value: ($float, $float)) -> Self {
+                Self {
+                    re: $real::new(value.0),
+                    im: $real::new(value.1),
+                }
+            }
+        }
 
-impl From<(f32, f32)> for Complex {
-    fn from(value: (f32, f32)) -> Self {
-        Self {
-            re: Real::new(value.0),
-            im: Real::new(value.1),
+        impl From<$real> for $complex {
+            fn from(value: $real) -> Self {
+                Self {
+                    re: value,
+                    im: $real::zero(),
+                }
+            }
         }
-    }
-}
 
-impl Add for Complex {
-    type Output = Self;
+        impl Add for $complex {
+            type Output = Self;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self {
-            re: self.re + rhs.re,
-            im: self.im + rhs.im,
+            fn add(self, rhs: Self) -> Self::Output {
+                Self {
+                    re: self.re + rhs.re,
+                    im: self.im + rhs.im,
+                }
+            }
         }
-    }
-}
 
-impl Zero for Complex {
-    fn zero() -> Self {
-        Self {
-            re: Real::zero(),
-            im: Real::zero(),
+        impl Zero for $complex {
+            fn zero() -> Self {
+                Self {
+                    re: $real::zero(),
+                    im: $real::zero(),
+                }
+            }
+
+            fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
+                self.re.is_zero(tolerance) && self.im.is_zero(tolerance)
+            }
         }
-    }
 
-    fn is_zero(&self, tolerance: f32) -> bool {
-        self.re.is_zero(tolerance) && self.im.is_zero(tolerance)
-    }
-}
+        impl FromStr for $complex {
+            type Err = StructureError;
+
+            /// Parses complex numbers in the format of [`Display`](std::fmt::Display), like
+            /// `+1+4i`, but also:
+            /// - With implicit parts or coefficients, like `-2i`, `i` or `3-i`.
+            /// - With `j` as the imaginary unit, and with exponents, like `1.5e-3-2j`.
+            /// - In polar form, as `r∠θ` (or `r∠θ°` for degrees) and `r*exp(iθ)`.
+            ///
+            /// Whitespaces are ignored.
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match ComplexLiteral::split(s)? {
+                    ComplexLiteral::Rectangular { re, im } => {
+                        Self::new($real::from_str(&re)?, $real::from_str(&im)?)
+                    }
+                    ComplexLiteral::Polar {
+                        modulus,
+                        argument,
+                        degrees,
+                    } => {
+                        let modulus = <$float>::from_str(&modulus)?;
+                        let argument = match degrees {
+                            true => <$float>::from_str(&argument)?.to_radians(),
+                            false => <$float>::from_str(&argument)?,
+                        };
+                        Self::from((modulus * argument.cos(), modulus * argument.sin()))
+                    }
+                })
+            }
+        }
 
-impl FromStr for Complex {
-    type Err = StructureError;
+        impl std::fmt::Display for $complex {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if self.re.is_zero(1e-12) {
+                    if self.im.is_zero(1e-12) {
+                        write!(f, "0")
+                    } else {
+                        write!(f, "{:+}i", self.im)
+                    }
+                } else if self.im.is_zero(1e-12) {
+                    write!(f, "{:+}", self.re)
+                } else {
+                    write!(f, "{:+}{:+}i", self.re, self.im)
+                }
+            }
+        }
 
-    fn from_str(_s: &str) -> Result<Self, Self::Err> {
-        todo!("Parser for complex numbers is not yet implemented")
-    }
-}
+        impl Equals for $complex {
+            fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+                self.re.equals(&rhs.re, tolerance) && self.im.equals(&rhs.im, tolerance)
+            }
+        }
+
+        impl Neg for $complex {
+            type Output = Self;
 
-impl std::fmt::Display for Complex {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.re.is_zero(1e-12) {
-            if self.im.is_zero(1e-12) {
-                write!(f, "0")
-            } else {
-                write!(f, "{:+}i", self.im)
+            fn neg(self) -> Self::Output {
+                Self {
+                    re: -self.re,
+                    im: -self.im,
+                }
             }
-        } else if self.im.is_zero(1e-12) {
-            write!(f, "{:+}", self.re)
-        } else {
-            write!(f, "{:+}{:+}i", self.re, self.im)
         }
-    }
+
+        impl Sub for $complex {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self {
+                    re: self.re - rhs.re,
+                    im: self.im - rhs.im,
+                }
+            }
+        }
+
+        impl FromF32 for $complex {
+            fn from_f32(value: f32, precision: f32) -> Self {
+                Self::from($real::from_f32(value, precision))
+            }
+        }
+
+        impl AsF32 for $complex {
+            /// The `as_f32` for complex numbers does not have sense. Hence, it should not be used.
+            ///
+            /// As a temporary workaround, it will return the modulus of the `Self`.
+            fn as_f32(&self) -> f32 {
+                self.modulus().as_f32()
+            }
+        }
+
+        impl AsF64 for $complex {
+            /// As [`AsF32`], it returns the modulus of the `Self`.
+            fn as_f64(&self) -> f64 {
+                self.modulus().as_f64()
+            }
+        }
+
+        impl Abs for $complex {
+            type Output = $complex;
+
+            fn abs_value(&self) -> Self::Output {
+                Self::from(self.modulus())
+            }
+        }
+
+        impl Group for $complex {
+            fn identity() -> Self {
+                Self::zero()
+            }
+
+            fn inverse(&self) -> Self {
+                Self::neg(*self)
+            }
+
+            fn op(&self, rhs: &Self) -> Self {
+                *self + *rhs
+            }
+        }
+
+        impl One for $complex {
+            fn one() -> Self {
+                Self {
+                    re: $real::one(),
+                    im: $real::zero(),
+                }
+            }
+
+            fn is_one<T: Tolerance>(&self, tolerance: T) -> bool {
+                self.re.is_one(tolerance) && self.im.is_zero(tolerance)
+            }
+        }
+
+        impl Mul for $complex {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self {
+                    re: self.re * rhs.re - self.im * rhs.im,
+                    im: self.re * rhs.im + self.im * rhs.re,
+                }
+            }
+        }
+
+        impl Div for $complex {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                let conj = rhs.conjugate();
+                let norm = rhs.re * rhs.re + rhs.im * rhs.im;
+                Self {
+                    re: (self * conj).re / norm,
+                    im: (self * conj).im / norm,
+                }
+            }
+        }
+
+        impl Ring for $complex {
+            fn sum(&self, rhs: &Self) -> Self {
+                *self + *rhs
+            }
+
+            fn mul(&self, rhs: &Self) -> Self {
+                *self * *rhs
+            }
+        }
+
+        impl CommutativeRing for $complex {}
+
+        impl IntegralDomain for $complex {
+            const IS_FIELD: bool = true;
+        }
+
+        impl Field for $complex {
+            fn inverse_multiplication(&self) -> Self {
+                let conj = self.conjugate();
+                let norm = self.re * self.re + self.im * self.im;
+                Self {
+                    re: conj.re / norm,
+                    im: conj.im / norm,
+                }
+            }
+        }
+
+        impl PartialOrd for $complex {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.modulus().partial_cmp(&other.modulus())
+            }
+        }
+    };
 }
 
-impl Equals for Complex {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
-        self.re.equals(&rhs.re, tolerance) && self.im.equals(&rhs.im, tolerance)
-    }
+complex_type!(Complex, Real, f32);
+
+complex_type!(
+    /// Double precision counterpart of [`Complex`], whose parts are [`Real64`].
+    Complex64,
+    Real64,
+    f64
+);
+
+/// Branch of the [`Complex::sqrt`], as every non-zero complex number has two square roots.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SqrtBranch {
+    /// The square root with positive real part, or positive imaginary part for the negative
+    /// reals. Its branch cut is the negative real axis, as for [`Complex::ln`].
+    Principal,
+    /// The opposite of the principal square root.
+    Opposite,
 }
 
-impl Neg for Complex {
-    type Output = Self;
+impl Complex {
+    /// Returns the argument, i.e., the angle with the positive real axis, in _(-π, π]_. It is
+    /// zero for zero.
+    pub fn argument(&self) -> Real {
+        self.im.atan2(self.re)
+    }
 
-    fn neg(self) -> Self::Output {
-        Self {
-            re: -self.re,
-            im: -self.im,
-        }
+    /// Returns the [polar form](https://en.wikipedia.org/wiki/Polar_coordinate_system#Complex_numbers)
+    /// _(r, θ)_, where _z = re<sup>iθ</sup>_, with the [`Complex::argument`] as _θ_.
+    pub fn to_polar(&self) -> (Real, Real) {
+        (self.modulus(), self.argument())
     }
-}
 
-impl Sub for Complex {
-    type Output = Self;
+    /// Returns the complex number _re<sup>iθ</sup>_.
+    pub fn from_polar(modulus: Real, argument: Real) -> Self {
+        Self::new(modulus * argument.cos(), modulus * argument.sin())
+    }
 
-    fn sub(self, rhs: Self) -> Self::Output {
-        Self {
-            re: self.re - rhs.re,
-            im: self.im - rhs.im,
-        }
+    /// Returns _e<sup>z</sup> = e<sup>a</sup>(cos b + i sin b)_, where _z = a + bi_.
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
     }
-}
 
-impl FromF32 for Complex {
-    fn from_f32(value: f32, _: f32) -> Self {
-        Self {
-            re: Real::new(value),
-            im: Real::zero(),
+    /// Returns the principal natural logarithm _ln|z| + i arg z_, whose imaginary part is in
+    /// _(-π, π]_. The real part is `-inf` for zero.
+    pub fn ln(&self) -> Self {
+        Self::new(self.modulus().ln(), self.argument())
+    }
+
+    /// Returns _z<sup>n</sup>_, by exponentiation by squaring, so it is exact for Gaussian
+    /// integers of moderate size.
+    pub fn powi(&self, n: i32) -> Self {
+        let mut base = match n < 0 {
+            true => self.inverse_multiplication(),
+            false => *self,
+        };
+        let mut exponent = n.unsigned_abs();
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
         }
+        result
     }
-}
 
-impl AsF32 for Complex {
-    /// The `as_f32` for complex numbers does not have sense. Hence, it should not be used.
+    /// Returns the principal value of _z<sup>x</sup> = e<sup>x ln z</sup>_ for a real exponent.
     ///
-    /// As a temporary workaround, it will return the modulus of the `Self`.
-    fn as_f32(&self) -> f32 {
-        self.modulus().as_f32()
+    /// For _z = 0_, as for [`f32::powf`], it is _1_ if _x = 0_, _0_ if _x > 0_ and a real infinity
+    /// if _x < 0_.
+    pub fn powf(&self, exponent: Real) -> Self {
+        if self.is_zero(f32::MIN_POSITIVE) {
+            return Self::from(Real::new(0_f32.powf(exponent.value())));
+        }
+        let (modulus, argument) = self.to_polar();
+        Self::from_polar(modulus.powf(exponent), argument * exponent)
     }
-}
 
-impl From<Real> for Complex {
-    fn from(value: Real) -> Self {
-        Self {
-            re: value,
-            im: Real::zero(),
+    /// Returns the principal value of _z<sup>w</sup> = e<sup>w ln z</sup>_.
+    ///
+    /// ## Example
+    ///
+    /// The result of _i<sup>i</sup>_ will be the real number _e<sup>-π/2</sup>_.
+    ///
+    /// For _z = 0_ and _w = a + bi_, it is _1_ if _w = 0_, _0_ if _a > 0_, a real infinity if
+    /// _a < 0_, and `NaN` if _a = 0_ and _b ≠ 0_, whose argument is undefined.
+    pub fn powc(&self, exponent: Complex) -> Self {
+        if self.is_zero(f32::MIN_POSITIVE) {
+            if exponent.re.value() == 0. && exponent.im.value() != 0. {
+                return Self::from((f32::NAN, f32::NAN));
+            }
+            return self.powf(exponent.re);
         }
+        (exponent * self.ln()).exp()
     }
-}
 
-impl Abs for Complex {
-    type Output = Complex;
+    /// Returns the square root in the given [`SqrtBranch`].
+    ///
+    /// It uses _√((|z| + a)/2)_ and _√((|z| - a)/2)_ for the parts, instead of the polar form, so
+    /// the square roots of perfect squares like _3 + 4i_ are exact.
+    pub fn sqrt(&self, branch: SqrtBranch) -> Self {
+        let modulus = self.modulus();
+        let two = Real::new(2.);
+        let re = ((modulus + self.re) / two).sqrt();
+        let im = ((modulus - self.re) / two).sqrt();
+        let principal = match self.im < Real::zero() {
+            true => Self::new(re, -im),
+            false => Self::new(re, im),
+        };
+        match branch {
+            SqrtBranch::Principal => principal,
+            SqrtBranch::Opposite => -principal,
+        }
+    }
 
-    fn abs_value(&self) -> Self::Output {
-        Self::from(self.modulus())
+    /// Returns the `n` distinct _n_-th roots of the number, starting with the principal one
+    /// and counterclockwise. It is empty if `n` is zero.
+    pub fn roots(&self, n: usize) -> Vec<Self> {
+        let (modulus, argument) = self.to_polar();
+        let modulus = modulus.powf(Real::new(1. / n as f32));
+        (0..n)
+            .map(|k| {
+                let angle = (argument.value() + 2. * std::f32::consts::PI * k as f32) / n as f32;
+                Self::from_polar(modulus, Real::new(angle))
+            })
+            .collect()
     }
-}
 
-impl Group for Complex {
-    fn identity() -> Self {
-        Self::zero()
+    /// Returns the `n` [roots of unity](https://en.wikipedia.org/wiki/Root_of_unity)
+    /// _e<sup>2πik/n</sup>_, for _k = 0, ..., n - 1_.
+    pub fn roots_of_unity(n: usize) -> Vec<Self> {
+        Self::one().roots(n)
     }
 
-    fn inverse(&self) -> Self {
-        Self::neg(*self)
+    /// Returns _sin(a + bi) = sin a cosh b + i cos a sinh b_.
+    pub fn sin(&self) -> Self {
+        Self::new(
+            self.re.sin() * self.im.cosh(),
+            self.re.cos() * self.im.sinh(),
+        )
     }
 
-    fn op(&self, rhs: &Self) -> Self {
-        *self + *rhs
+    /// Returns _cos(a + bi) = cos a cosh b - i sin a sinh b_.
+    pub fn cos(&self) -> Self {
+        Self::new(
+            self.re.cos() * self.im.cosh(),
+            -(self.re.sin() * self.im.sinh()),
+        )
     }
-}
 
-impl One for Complex {
-    fn one() -> Self {
-        Self {
-            re: Real::one(),
-            im: Real::zero(),
-        }
+    pub fn tan(&self) -> Self {
+        self.sin() / self.cos()
     }
 
-    fn is_one(&self, tolerance: f32) -> bool {
-        self.re.is_one(tolerance) && self.im.is_zero(tolerance)
+    /// Returns _sinh(a + bi) = sinh a cos b + i cosh a sin b_.
+    pub fn sinh(&self) -> Self {
+        Self::new(
+            self.re.sinh() * self.im.cos(),
+            self.re.cosh() * self.im.sin(),
+        )
     }
-}
 
-impl Mul for Complex {
-    type Output = Self;
+    /// Returns _cosh(a + bi) = cosh a cos b + i sinh a sin b_.
+    pub fn cosh(&self) -> Self {
+        Self::new(
+            self.re.cosh() * self.im.cos(),
+            self.re.sinh() * self.im.sin(),
+        )
+    }
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        Self {
-            re: self.re * rhs.re - self.im * rhs.im,
-            im: self.re * rhs.im + self.im * rhs.re,
-        }
+    pub fn tanh(&self) -> Self {
+        self.sinh() / self.cosh()
     }
 }
 
-impl Rem for Complex {
-    type Output = Self;
-
-    fn rem(self, _: Self) -> Self::Output {
-        todo!()
+impl<R> From<Rational<R>> for Complex
+where
+    R: EuclideanDomain + PartialOrd + AsF32,
+{
+    fn from(value: Rational<R>) -> Self {
+        Self::from(Real::new(value.as_f32()))
     }
 }
 
-impl Div for Complex {
-    type Output = Self;
+impl<R> From<Integer<R>> for Complex
+where
+    R: EuclideanDomain + PartialOrd + AsF32,
+{
+    fn from(value: Integer<R>) -> Self {
+        Self::from(Real::new(value.as_f32()))
+    }
+}
 
-    fn div(self, rhs: Self) -> Self::Output {
-        let conj = rhs.conjugate();
-        let norm = rhs.re * rhs.re + rhs.im * rhs.im;
-        Self {
-            re: (self * conj).re / norm,
-            im: (self * conj).im / norm,
-        }
+impl From<Real> for Complex64 {
+    fn from(value: Real) -> Self {
+        Self::from(Real64::from(value))
     }
 }
 
-impl Ring for Complex {
-    fn sum(&self, rhs: &Self) -> Self {
-        *self + *rhs
+impl<R> From<Rational<R>> for Complex64
+where
+    R: EuclideanDomain + PartialOrd + AsF64,
+{
+    fn from(value: Rational<R>) -> Self {
+        Self::from(Real64::new(value.as_f64()))
     }
+}
 
-    fn mul(&self, rhs: &Self) -> Self {
-        *self * *rhs
+impl<R> From<Integer<R>> for Complex64
+where
+    R: EuclideanDomain + PartialOrd + AsF64,
+{
+    fn from(value: Integer<R>) -> Self {
+        Self::from(Real64::new(value.as_f64()))
     }
 }
 
-impl Field for Complex {
-    fn inverse_multiplication(&self) -> Self {
-        let conj = self.conjugate();
-        let norm = self.re * self.re + self.im * self.im;
+impl From<Complex> for Complex64 {
+    fn from(value: Complex) -> Self {
         Self {
-            re: conj.re / norm,
-            im: conj.im / norm,
+            re: Real64::from(value.re),
+            im: Real64::from(value.im),
         }
     }
 }
 
-impl PartialOrd for Complex {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let self_module = self.modulus();
-        let other_module = other.modulus();
-        self_module.partial_cmp(&other_module)
+/// Parts of a complex number literal, as they are written, before parsing them as numbers.
+pub(super) enum ComplexLiteral {
+    Rectangular {
+        re: String,
+        im: String,
+    },
+    /// The argument is in radians, unless it ends with `°`.
+    Polar {
+        modulus: String,
+        argument: String,
+        degrees: bool,
+    },
+}
+
+impl ComplexLiteral {
+    /// Splits the literal in its parts, which may be implicit, like the `1` of `-i`. The
+    /// imaginary unit can be written as `i` or `j`.
+    pub(super) fn split(s: &str) -> Result<Self, StructureError> {
+        let input = s.split_whitespace().collect::<String>();
+        let error = || StructureError::ParseError(format!("Invalid complex number: {s}"));
+        // Implicit coefficients, like the modulus of `exp(iθ)` or the imaginary part of `3-i`.
+        let implicit = |value: &str| match value.trim_end_matches('*') {
+            "" | "+" => "1".to_string(),
+            "-" => "-1".to_string(),
+            value => value.to_string(),
+        };
+
+        if let Some((modulus, argument)) = input.split_once('∠') {
+            let (argument, degrees) = match argument.strip_suffix('°') {
+                Some(argument) => (argument, true),
+                None => (argument, false),
+            };
+            return Ok(Self::Polar {
+                modulus: implicit(modulus),
+                argument: argument.to_string(),
+                degrees,
+            });
+        }
+        if let Some((modulus, exponent)) = input.split_once("exp(") {
+            let exponent = exponent.strip_suffix(')').ok_or_else(error)?;
+            let argument = ["i*", "j*", "i", "j"]
+                .into_iter()
+                .find_map(|unit| exponent.strip_prefix(unit))
+                .or_else(|| {
+                    ["*i", "*j", "i", "j"]
+                        .into_iter()
+                        .find_map(|unit| exponent.strip_suffix(unit))
+                })
+                .ok_or_else(error)?;
+            return Ok(Self::Polar {
+                modulus: implicit(modulus),
+                argument: argument.to_string(),
+                degrees: false,
+            });
+        }
+
+        let Some(imaginary) = input.strip_suffix(['i', 'j']) else {
+            return Ok(Self::Rectangular {
+                re: input,
+                im: "0".to_string(),
+            });
+        };
+        // The sign that separates both parts is the last one that is not the leading sign nor
+        // the sign of an exponent.
+        let split = imaginary
+            .char_indices()
+            .rev()
+            .find(|&(i, character)| {
+                matches!(character, '+' | '-') && i > 0 && !imaginary[..i].ends_with(['e', 'E'])
+            })
+            .map(|(i, _)| i);
+        Ok(match split {
+            Some(i) => Self::Rectangular {
+                re: imaginary[..i].to_string(),
+                im: implicit(&imaginary[i..]),
+            },
+            None => Self::Rectangular {
+                re: "0".to_string(),
+                im: implicit(imaginary),
+            },
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::str::FromStr;
+
     use crate::{
         equality::Equals,
-        identities::One,
-        structures::{complex::Complex, Field},
+        identities::{One, Zero},
+        matrix::{generic::Matrix, MatrixError},
+        structures::{
+            complex::{Complex, Complex64, SqrtBranch},
+            reals::Real,
+            Field,
+        },
     };
 
     #[test]
@@ -316,4 +681,253 @@ mod test {
         assert!((z_3.inverse_multiplication() * z_3).is_one(TOL));
         assert!((z_4.inverse_multiplication() * z_4).is_one(TOL));
     }
+
+    #[test]
+    fn complex64_works_as_expected() {
+        let z = Complex64::from((1., 4.));
+        let w = Complex64::from((1. / 3., -1e-10));
+        assert!((z * z.conjugate()).equals(&Complex64::from((17., 0.)), 1e-14));
+        assert!((z.inverse_multiplication() * z).is_one(1e-15));
+        assert!(((z / w) * w).equals(&z, 1e-14));
+        assert!(!w.equals(&Complex64::from((1. / 3., 0.)), 1e-12));
+        assert!(Complex64::from(Complex::from((1., 4.))).equals(&z, 1e-15));
+        pretty_assertions::assert_eq!(z.to_string(), "+1+4i");
+    }
+
+    #[test]
+    fn parse_works_as_expected() {
+        struct TestCase {
+            id: &'static str,
+            input: &'static str,
+            expected: (f32, f32),
+        }
+        vec![
+            TestCase {
+                id: "Display format",
+                input: "+1+4i",
+                expected: (1., 4.),
+            },
+            TestCase {
+                id: "Rectangular",
+                input: "3-4i",
+                expected: (3., -4.),
+            },
+            TestCase {
+                id: "Pure imaginary",
+                input: "-2i",
+                expected: (0., -2.),
+            },
+            TestCase {
+                id: "Imaginary unit",
+                input: "i",
+                expected: (0., 1.),
+            },
+            TestCase {
+                id: "Implicit imaginary coefficient",
+                input: "3 - i",
+                expected: (3., -1.),
+            },
+            TestCase {
+                id: "Real",
+                input: "-2.5",
+                expected: (-2.5, 0.),
+            },
+            TestCase {
+                id: "Exponents and j",
+                input: "1.5e-3-2j",
+                expected: (1.5e-3, -2.),
+            },
+            TestCase {
+                id: "Exponent in the imaginary part",
+                input: "-1e+2+2E-1*i",
+                expected: (-100., 0.2),
+            },
+            TestCase {
+                id: "Polar",
+                input: "2∠1.5707964",
+                expected: (0., 2.),
+            },
+            TestCase {
+                id: "Polar in degrees",
+                input: "2∠-60°",
+                expected: (1., -3_f32.sqrt()),
+            },
+            TestCase {
+                id: "Exponential",
+                input: "2*exp(i3.1415927)",
+                expected: (-2., 0.),
+            },
+            TestCase {
+                id: "Exponential with implicit modulus",
+                input: "exp(0.5*i)",
+                expected: (0.5_f32.cos(), 0.5_f32.sin()),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let parsed = Complex::from_str(test.input).unwrap();
+            assert!(
+                parsed.equals(&Complex::from(test.expected), 1e-6),
+                "Test case: {} failed, got {}",
+                test.id,
+                parsed
+            );
+        });
+
+        for invalid in ["", "1+2k", "2∠", "exp(2)", "1+i+i", "i3"] {
+            assert!(Complex::from_str(invalid).is_err(), "{invalid} parsed");
+        }
+    }
+
+    #[test]
+    fn parse_round_trips_with_display() {
+        [
+            (1., 4.),
+            (1., -4.),
+            (-1., 4.),
+            (-1., 0.),
+            (0., -2.5),
+            (0., 0.),
+            (1e-3, 2e10),
+        ]
+        .into_iter()
+        .for_each(|parts| {
+            let z = Complex::from(parts);
+            pretty_assertions::assert_eq!(Complex::from_str(&z.to_string()).unwrap(), z);
+            let w = Complex64::from((parts.0 as f64, parts.1 as f64));
+            pretty_assertions::assert_eq!(Complex64::from_str(&w.to_string()).unwrap(), w);
+        });
+    }
+
+    #[test]
+    fn parse_complex_matrix() {
+        let matrix = Matrix::<Complex>::from_str("{{1 + 2i, -i}, {3, 2∠0}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.data,
+            vec![
+                vec![Complex::from((1., 2.)), Complex::from((0., -1.))],
+                vec![Complex::from((3., 0.)), Complex::from((2., 0.))],
+            ]
+        );
+        assert!(matches!(
+            Matrix::<Complex>::from_str("{{1+2k}}"),
+            Err(MatrixError::MatrixError(_))
+        ));
+    }
+
+    #[test]
+    fn exponential_and_logarithm() {
+        let pi = std::f32::consts::PI;
+        assert!(Complex::from((0., pi))
+            .exp()
+            .equals(&Complex::from((-1., 0.)), 1e-6));
+        assert!(Complex::from((-1., 0.))
+            .ln()
+            .equals(&Complex::from((0., pi)), 1e-6));
+        let z = Complex::from((-3., 4.));
+        assert!(z.ln().exp().equals(&z, 1e-5));
+        let (modulus, argument) = z.to_polar();
+        pretty_assertions::assert_eq!(modulus, Real::new(5.));
+        assert!(Complex::from_polar(modulus, argument).equals(&z, 1e-5));
+        assert!(Complex::from((0., -2.))
+            .argument()
+            .equals(&Real::new(-pi / 2.), 1e-6));
+    }
+
+    #[test]
+    fn powers_and_roots() {
+        let z = Complex::from((1., 1.));
+        pretty_assertions::assert_eq!(z.powi(3), Complex::from((-2., 2.)));
+        assert!(z.powi(-2).equals(&Complex::from((0., -0.5)), 1e-6));
+        assert!(z
+            .powf(Real::new(0.5))
+            .equals(&z.sqrt(SqrtBranch::Principal), 1e-6));
+        let i = Complex::from((0., 1.));
+        let expected = (-std::f32::consts::FRAC_PI_2).exp();
+        assert!(i.powc(i).equals(&Complex::from((expected, 0.)), 1e-6));
+        let zero = Complex::zero();
+        pretty_assertions::assert_eq!(zero.powf(Real::new(0.)), Complex::one());
+        pretty_assertions::assert_eq!(zero.powf(Real::new(2.)), zero);
+        pretty_assertions::assert_eq!(zero.powf(Real::new(-2.)).re().value(), f32::INFINITY);
+        pretty_assertions::assert_eq!(zero.powc(zero), Complex::one());
+        pretty_assertions::assert_eq!(zero.powc(Complex::from((1., 1.))), zero);
+        pretty_assertions::assert_eq!(
+            zero.powc(Complex::from((-1., 1.))),
+            Complex::from((f32::INFINITY, 0.))
+        );
+        assert!(zero.powc(i).re().value().is_nan());
+
+        struct TestCase {
+            id: &'static str,
+            z: (f32, f32),
+            principal: (f32, f32),
+        }
+        vec![
+            TestCase {
+                id: "Perfect square",
+                z: (3., 4.),
+                principal: (2., 1.),
+            },
+            TestCase {
+                id: "Negative real",
+                z: (-4., 0.),
+                principal: (0., 2.),
+            },
+            TestCase {
+                id: "Lower half plane",
+                z: (0., -2.),
+                principal: (1., -1.),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let z = Complex::from(test.z);
+            let principal = Complex::from(test.principal);
+            pretty_assertions::assert_eq!(
+                z.sqrt(SqrtBranch::Principal),
+                principal,
+                "Test case: {} failed",
+                test.id
+            );
+            pretty_assertions::assert_eq!(
+                z.sqrt(SqrtBranch::Opposite),
+                -principal,
+                "Test case: {} failed",
+                test.id
+            );
+        });
+
+        let roots = Complex::roots_of_unity(6);
+        pretty_assertions::assert_eq!(roots.len(), 6);
+        assert!(roots
+            .iter()
+            .fold(Complex::zero(), |sum, root| sum + *root)
+            .is_zero(1e-6));
+        roots
+            .iter()
+            .for_each(|root| assert!(root.powi(6).is_one(1e-5)));
+        let cube_roots = Complex::from((-8., 0.)).roots(3);
+        assert!(cube_roots[0].equals(&Complex::from((1., 3_f32.sqrt())), 1e-5));
+        assert!(cube_roots[1].equals(&Complex::from((-2., 0.)), 1e-5));
+    }
+
+    #[test]
+    fn trigonometric_and_hyperbolic_functions() {
+        let z = Complex::from((1., 1.));
+        let cases = [
+            (z.sin(), (1.29846, 0.63496)),
+            (z.cos(), (0.83373, -0.98890)),
+            (z.tan(), (0.27175, 1.08392)),
+            (z.sinh(), (0.63496, 1.29846)),
+            (z.cosh(), (0.83373, 0.98890)),
+            (z.tanh(), (1.08392, 0.27175)),
+        ];
+        for (computed, expected) in cases {
+            assert!(
+                computed.equals(&Complex::from(expected), 1e-4),
+                "got {computed}"
+            );
+        }
+        assert!((z.sin() * z.sin() + z.cos() * z.cos()).is_one(1e-5));
+    }
 }
@@ -0,0 +1,595 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use super::{
+    complex::ComplexLiteral, errors::StructureError, integers::Integer, CommutativeRing,
+    EuclideanDomain, Group, IntegralDomain, Ring,
+};
+use crate::{
+    arithmetics::euclid,
+    equality::{Equals, Tolerance},
+    identities::{One, Zero},
+    num_types::AsF32,
+    traits::Abs,
+};
+
+/// Representation of a [Gaussian integer](https://en.wikipedia.org/wiki/Gaussian_integer)
+/// _a + bi_, where _a_ and _b_ are [`Integer`]s. It is the exact counterpart of
+/// [`Complex`](super::complex::Complex).
+///
+/// The Gaussian integers _Z\[i\]_ are a Euclidean domain with the norm _N(a + bi) = a² + b²_, so
+/// the algorithms of [`euclid`] work for them. The division rounds the exact quotient to the
+/// closest Gaussian integer, so the remainder has at most half the norm of the divisor.
+///
+/// As for [`Complex`](super::complex::Complex), the order compares the norms, and the absolute
+/// value is the associate in the first quadrant, i.e., with _a > 0_ and _b ≥ 0_.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    re: Integer<R>,
+    im: Integer<R>,
+}
+
+impl<R> GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    pub fn new(re: Integer<R>, im: Integer<R>) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the imaginary unit _i_.
+    pub fn i() -> Self {
+        Self::new(Integer::zero(), Integer::one())
+    }
+
+    /// Returns the real part.
+    pub fn re(&self) -> &Integer<R> {
+        &self.re
+    }
+
+    /// Returns the imaginary part.
+    pub fn im(&self) -> &Integer<R> {
+        &self.im
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.re.clone(), -self.im.clone())
+    }
+
+    /// Returns the norm _N(a + bi) = a² + b²_, which is multiplicative, or [`None`] if it cannot
+    /// be represented, see [`Ring::checked_mul`].
+    pub fn norm_value(&self) -> Option<Integer<R>> {
+        self.re
+            .checked_mul(&self.re)
+            .zip(self.im.checked_mul(&self.im))
+            .and_then(|(re, im)| re.checked_add(&im))
+    }
+
+    /// Returns the units of _Z\[i\]_, which are _1_, _i_, _-1_ and _-i_.
+    pub fn units() -> [Self; 4] {
+        [Self::one(), Self::i(), -Self::one(), -Self::i()]
+    }
+
+    /// Checks if the number is a unit, i.e., if its norm is one.
+    pub fn is_unit(&self) -> bool {
+        self.norm_value().is_some_and(|norm| norm.is_one(0.))
+    }
+
+    /// Returns the associate in the first quadrant, i.e., with positive real part and
+    /// non-negative imaginary part, or zero for zero.
+    pub fn normalized(&self) -> Self {
+        if self.is_zero(0.) {
+            return Self::zero();
+        }
+        Self::units()
+            .into_iter()
+            .map(|unit| self.clone() * unit)
+            .find(|associate| associate.re > Integer::zero() && associate.im >= Integer::zero())
+            .expect("Every non-zero Gaussian integer has an associate in the first quadrant")
+    }
+
+    /// Returns the greatest common divisor, computed with [`euclid::gcd`] and
+    /// [`normalized`](GaussianInteger::normalized), as it is only unique up to a unit.
+    ///
+    /// ## Example
+    ///
+    /// The result for _11 + 3i_ and _1 + 8i_ will be _2 + i_.
+    pub fn gcd(&self, other: &Self) -> Self {
+        euclid::gcd(self, other).normalized()
+    }
+
+    /// Checks if the number is a Gaussian prime, i.e., if it is not zero nor a unit, and it has
+    /// no divisors but its associates and the units. By the classification of the Gaussian
+    /// primes, _a + bi_ is prime if and only if:
+    /// - Both _a_ and _b_ are non-zero and the norm _a² + b²_ is a prime number.
+    /// - One of them is zero and the absolute value of the other is a prime number _p_ with
+    ///   _p ≡ 3 (mod 4)_.
+    ///
+    /// For instance, _1 + i_ and _3_ are prime, but _2 = -i(1 + i)²_ and _5 = (2 + i)(2 - i)_ are
+    /// not.
+    ///
+    /// It returns [`None`] if both parts are non-zero and the norm cannot be represented, see
+    /// [`norm_value`](GaussianInteger::norm_value).
+    ///
+    /// ## Complexity
+    /// It uses trial division, so the complexity is _O(√N)_, where _N_ is the norm.
+    pub fn is_prime(&self) -> Option<bool> {
+        let four = (0..4).fold(Integer::zero(), |sum, _| sum + Integer::one());
+        let three = four.clone() - Integer::one();
+        match (self.re.is_zero(0.), self.im.is_zero(0.)) {
+            (false, false) => self.norm_value().map(|norm| is_rational_prime(&norm)),
+            (true, true) => Some(false),
+            (true, false) | (false, true) => {
+                let value = self.re.clone() + self.im.clone();
+                // The absolute value of the minimum of a primitive type overflows, but it is a
+                // power of two, so it is not prime.
+                let value = if value < Integer::zero() {
+                    Integer::zero().checked_sub(&value)
+                } else {
+                    Some(value)
+                };
+                Some(value.is_some_and(|value| {
+                    is_rational_prime(&value) && value.div_rem(&four).1 == three
+                }))
+            }
+        }
+    }
+}
+
+/// Checks if a non-negative [`Integer`] is prime, by trial division.
+fn is_rational_prime<R>(n: &Integer<R>) -> bool
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    let two = Integer::<R>::one() + Integer::one();
+    if *n < two {
+        return false;
+    }
+    let mut divisor = two;
+    // The square of the divisor may overflow, so it is compared with the quotient instead.
+    while divisor <= n.div_rem(&divisor).0 {
+        if n.div_rem(&divisor).1.is_zero(0.) {
+            return false;
+        }
+        divisor = divisor + Integer::one();
+    }
+    true
+}
+
+impl<R> From<Integer<R>> for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn from(value: Integer<R>) -> Self {
+        Self::new(value, Integer::zero())
+    }
+}
+
+impl<R> From<(R, R)> for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn from(value: (R, R)) -> Self {
+        Self::new(Integer::new(value.0), Integer::new(value.1))
+    }
+}
+
+impl<R> Display for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    /// Writes the number like `3+4i`, `3-i`, `-2i` or `5`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im.is_zero(0.) {
+            return write!(f, "{}", self.re);
+        }
+        if !self.re.is_zero(0.) {
+            write!(f, "{}", self.re)?;
+            if self.im > Integer::zero() {
+                write!(f, "+")?;
+            }
+        }
+        if self.im.is_one(0.) {
+            write!(f, "i")
+        } else if (-self.im.clone()).is_one(0.) {
+            write!(f, "-i")
+        } else {
+            write!(f, "{}i", self.im)
+        }
+    }
+}
+
+impl<R> FromStr for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Err = StructureError;
+
+    /// Parses the rectangular formats of [`Complex`](super::complex::Complex) with integer
+    /// parts, like `3+4i`, `-2i`, `i` or `5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match ComplexLiteral::split(s)? {
+            ComplexLiteral::Rectangular { re, im } => {
+                Ok(Self::new(Integer::from_str(&re)?, Integer::from_str(&im)?))
+            }
+            ComplexLiteral::Polar { .. } => Err(StructureError::ParseError(format!(
+                "Gaussian integers cannot be written in polar form: {s}"
+            ))),
+        }
+    }
+}
+
+impl<R> Add for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<R> Neg for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl<R> Sub for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<R> Mul for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone(),
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<R> Div for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    /// Returns the quotient of [`EuclideanDomain::div_rem`], which is exact if `rhs` divides
+    /// `self`.
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<R> Rem for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    /// Returns the remainder of [`EuclideanDomain::div_rem`].
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<R> Zero for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn zero() -> Self {
+        Self::new(Integer::zero(), Integer::zero())
+    }
+
+    fn is_zero<T: Tolerance>(&self, _: T) -> bool {
+        self.re.is_zero(0.) && self.im.is_zero(0.)
+    }
+}
+
+impl<R> One for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn one() -> Self {
+        Self::new(Integer::one(), Integer::zero())
+    }
+
+    fn is_one<T: Tolerance>(&self, _: T) -> bool {
+        self.re.is_one(0.) && self.im.is_zero(0.)
+    }
+}
+
+impl<R> Equals for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn equals<T: Tolerance>(&self, rhs: &Self, _: T) -> bool {
+        self == rhs
+    }
+}
+
+impl<R> PartialOrd for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match (self.norm_value(), other.norm_value()) {
+            (Some(norm), Some(other_norm)) => norm.partial_cmp(&other_norm),
+            _ => self.as_f32().partial_cmp(&other.as_f32()),
+        }
+    }
+}
+
+impl<R> AsF32 for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    /// As for [`Complex`](super::complex::Complex), it returns the modulus of the `Self`.
+    fn as_f32(&self) -> f32 {
+        self.re.as_f32().hypot(self.im.as_f32())
+    }
+}
+
+impl<R> Abs for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Output = Self;
+
+    fn abs_value(&self) -> Self::Output {
+        self.normalized()
+    }
+}
+
+impl<R> Group for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        -self.clone()
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+}
+
+impl<R> Ring for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn sum(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.clone() * rhs.clone()
+    }
+}
+
+impl<R> CommutativeRing for GaussianInteger<R> where R: EuclideanDomain + PartialOrd {}
+
+impl<R> IntegralDomain for GaussianInteger<R> where R: EuclideanDomain + PartialOrd {}
+
+impl<R> EuclideanDomain for GaussianInteger<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Norm = R::Norm;
+
+    /// The Euclidean function of the Gaussian integers is the norm _a² + b²_.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the norm cannot be represented, see [`GaussianInteger::norm_value`].
+    fn norm(&self) -> Self::Norm {
+        self.norm_value()
+            .expect("The norm of a Gaussian integer overflows")
+            .norm()
+    }
+
+    /// Returns the quotient and the remainder of the division, where the quotient is the exact
+    /// quotient _self · conj(rhs) / N(rhs)_ with both parts rounded to the closest integer.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero or its norm cannot be represented.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(0.), "Division by zero");
+        let numerator = self.clone() * rhs.conjugate();
+        let denominator = rhs
+            .norm_value()
+            .expect("The norm of a Gaussian integer overflows");
+        let two = Integer::<R>::one() + Integer::one();
+        // With a positive divisor, the Euclidean division is the floor, so ⌊x/n + 1/2⌋ is the
+        // closest integer to x/n.
+        let round = |x: Integer<R>| {
+            (two.clone() * x + denominator.clone())
+                .div_rem(&(two.clone() * denominator.clone()))
+                .0
+        };
+        let quotient = Self::new(round(numerator.re), round(numerator.im));
+        let remainder = self.clone() - rhs.clone() * quotient.clone();
+        (quotient, remainder)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::GaussianInteger;
+    use crate::{
+        arithmetics::euclid,
+        identities::{One, Zero},
+        structures::{integers::Integer, EuclideanDomain},
+    };
+
+    fn gaussian(re: i64, im: i64) -> GaussianInteger<i64> {
+        GaussianInteger::from((re, im))
+    }
+
+    #[test]
+    fn parse_and_display() {
+        vec![
+            ("3+4i", gaussian(3, 4)),
+            ("3-i", gaussian(3, -1)),
+            ("-2i", gaussian(0, -2)),
+            ("i", gaussian(0, 1)),
+            ("-i", gaussian(0, -1)),
+            ("5", gaussian(5, 0)),
+            ("0", gaussian(0, 0)),
+        ]
+        .into_iter()
+        .for_each(|(input, expected)| {
+            let parsed = GaussianInteger::<i64>::from_str(input).unwrap();
+            pretty_assertions::assert_eq!(parsed, expected, "Test case: {} failed", input);
+            pretty_assertions::assert_eq!(parsed.to_string(), input);
+        });
+        assert!(GaussianInteger::<i64>::from_str("1.5+i").is_err());
+        assert!(GaussianInteger::<i64>::from_str("2∠1").is_err());
+    }
+
+    #[test]
+    fn euclidean_division() {
+        struct TestCase {
+            id: &'static str,
+            dividend: GaussianInteger<i64>,
+            divisor: GaussianInteger<i64>,
+        }
+        vec![
+            TestCase {
+                id: "Exact",
+                dividend: gaussian(5, 0),
+                divisor: gaussian(2, 1),
+            },
+            TestCase {
+                id: "With remainder",
+                dividend: gaussian(27, -23),
+                divisor: gaussian(8, 1),
+            },
+            TestCase {
+                id: "Negative parts",
+                dividend: gaussian(-7, -11),
+                divisor: gaussian(-2, 3),
+            },
+            TestCase {
+                id: "Real divisor",
+                dividend: gaussian(7, 5),
+                divisor: gaussian(2, 0),
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let (quotient, remainder) = test.dividend.div_rem(&test.divisor);
+            pretty_assertions::assert_eq!(
+                test.divisor * quotient + remainder,
+                test.dividend,
+                "Test case: {} failed",
+                test.id
+            );
+            assert!(
+                remainder.norm() * 2 <= test.divisor.norm(),
+                "Test case: {} failed",
+                test.id
+            );
+        });
+        pretty_assertions::assert_eq!(gaussian(5, 0) / gaussian(2, 1), gaussian(2, -1));
+        pretty_assertions::assert_eq!(gaussian(5, 0) % gaussian(2, 1), GaussianInteger::zero());
+    }
+
+    #[test]
+    fn gcd_and_units() {
+        pretty_assertions::assert_eq!(gaussian(11, 3).gcd(&gaussian(1, 8)), gaussian(2, 1));
+        pretty_assertions::assert_eq!(gaussian(4, 0).gcd(&gaussian(0, 6)), gaussian(2, 0));
+        pretty_assertions::assert_eq!(gaussian(3, 0).gcd(&gaussian(2, 1)), gaussian(1, 0));
+        let (d, x, y) = euclid::extended_gcd(&gaussian(11, 3), &gaussian(1, 8));
+        pretty_assertions::assert_eq!(gaussian(11, 3) * x + gaussian(1, 8) * y, d);
+
+        GaussianInteger::<i64>::units()
+            .iter()
+            .for_each(|unit| assert!(unit.is_unit()));
+        pretty_assertions::assert_eq!(
+            GaussianInteger::<i64>::i() * GaussianInteger::i(),
+            -GaussianInteger::one()
+        );
+        assert!(!gaussian(1, 1).is_unit());
+        pretty_assertions::assert_eq!(gaussian(-3, -2).normalized(), gaussian(3, 2));
+        pretty_assertions::assert_eq!(gaussian(0, -4).normalized(), gaussian(4, 0));
+        pretty_assertions::assert_eq!(gaussian(3, 4).norm_value(), Some(Integer::new(25)));
+        pretty_assertions::assert_eq!(
+            GaussianInteger::<i8>::new(Integer::new(10), Integer::new(10)).norm_value(),
+            None
+        );
+    }
+
+    #[test]
+    fn primality() {
+        [
+            gaussian(1, 1),
+            gaussian(3, 0),
+            gaussian(0, -7),
+            gaussian(2, 1),
+            gaussian(4, -5),
+        ]
+        .into_iter()
+        .for_each(|prime| assert_eq!(prime.is_prime(), Some(true), "{prime} should be prime"));
+        [
+            gaussian(0, 0),
+            gaussian(0, 1),
+            gaussian(2, 0),
+            gaussian(5, 0),
+            gaussian(0, 13),
+            gaussian(3, 3),
+            gaussian(4, 3),
+        ]
+        .into_iter()
+        .for_each(|composite| {
+            assert_eq!(
+                composite.is_prime(),
+                Some(false),
+                "{composite} should not be prime"
+            )
+        });
+    }
+
+    #[test]
+    fn primality_near_the_limits() {
+        let gaussian = |re: i32, im: i32| GaussianInteger::new(Integer::new(re), Integer::new(im));
+        pretty_assertions::assert_eq!(gaussian(i32::MAX, 0).is_prime(), Some(true));
+        pretty_assertions::assert_eq!(gaussian(0, i32::MAX - 1).is_prime(), Some(false));
+        pretty_assertions::assert_eq!(gaussian(i32::MIN, 0).is_prime(), Some(false));
+        pretty_assertions::assert_eq!(gaussian(i32::MAX, 1).is_prime(), None);
+        pretty_assertions::assert_eq!(gaussian(46_340, 2).is_prime(), Some(false));
+        assert!(!gaussian(i32::MAX, 1).is_unit());
+        assert!(gaussian(i32::MAX, 1) > gaussian(46_340, 1));
+    }
+}
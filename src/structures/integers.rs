@@ -4,12 +4,14 @@ use std::{
     str::FromStr,
 };
 
-use super::{errors::StructureError, Group, Ring};
+use super::{
+    errors::StructureError, CheckedRing, CommutativeRing, EuclideanDomain, Group, IntegralDomain,
+    Ring,
+};
 use crate::{
-    arithmetics::euclid::quotient,
-    equality::Equals,
+    equality::{Equals, Tolerance},
     identities::{One, Zero},
-    num_types::{AsF32, FromF32},
+    num_types::{AsF32, AsF64, FromF32},
     traits::Abs,
 };
 
@@ -17,14 +19,14 @@ use crate::{
 /// Representation of an integer number.
 pub struct Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     value: R,
 }
 
 impl<R> Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     /// Returns a new instance of [`Integer`], given a value that can be
     /// any of the following types: [`isize`], [`i8`], [`i16`], [`i32`], [`i64`], [`i128`].
@@ -40,7 +42,7 @@ where
 
 impl<R> From<R> for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn from(value: R) -> Self {
         Self { value }
@@ -49,7 +51,7 @@ where
 
 impl<R> Display for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.value)
@@ -58,7 +60,7 @@ where
 
 impl<R> Add for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -71,7 +73,7 @@ where
 
 impl<R> Mul for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -84,7 +86,7 @@ where
 
 impl<R> Rem for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -97,7 +99,7 @@ where
 
 impl<R> Neg for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -108,7 +110,7 @@ where
 
 impl<R> Sub for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -121,33 +123,33 @@ where
 
 impl<R> Zero for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn zero() -> Self {
         Self::new(Zero::zero())
     }
 
-    fn is_zero(&self, _: f32) -> bool {
+    fn is_zero<T: Tolerance>(&self, _: T) -> bool {
         self.value.is_zero(0_f32)
     }
 }
 
 impl<R> One for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn one() -> Self {
         Self::new(One::one())
     }
 
-    fn is_one(&self, _: f32) -> bool {
+    fn is_one<T: Tolerance>(&self, _: T) -> bool {
         self.value.is_one(0_f32)
     }
 }
 
 impl<R> FromStr for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Err = StructureError;
 
@@ -160,25 +162,34 @@ where
 
 impl<R> Equals for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
         self.value.equals(&rhs.value, tolerance)
     }
 }
 
 impl<R> AsF32 for Integer<R>
 where
-    R: Ring + PartialOrd + AsF32,
+    R: EuclideanDomain + PartialOrd + AsF32,
 {
     fn as_f32(&self) -> f32 {
         self.value.as_f32()
     }
 }
 
+impl<R> AsF64 for Integer<R>
+where
+    R: EuclideanDomain + PartialOrd + AsF64,
+{
+    fn as_f64(&self) -> f64 {
+        self.value.as_f64()
+    }
+}
+
 impl<R> FromF32 for Integer<R>
 where
-    R: Ring + PartialOrd + FromF32,
+    R: EuclideanDomain + PartialOrd + FromF32,
 {
     fn from_f32(value: f32, tolerance: f32) -> Self {
         Self::new(R::from_f32(value, tolerance))
@@ -187,7 +198,7 @@ where
 
 impl<R> Group for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn identity() -> Self {
         Self::new(Zero::zero())
@@ -204,18 +215,18 @@ where
 
 impl<R> Div for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        quotient(&self, &rhs)
+        Self::new(self.value / rhs.value)
     }
 }
 
 impl<R> Abs for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -227,7 +238,7 @@ where
 
 impl<R> Ring for Integer<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn sum(&self, rhs: &Self) -> Self {
         self.clone() + rhs.clone()
@@ -236,6 +247,65 @@ where
     fn mul(&self, rhs: &Self) -> Self {
         self.clone() * rhs.clone()
     }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_add(&rhs.value).map(Self::new)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_sub(&rhs.value).map(Self::new)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_mul(&rhs.value).map(Self::new)
+    }
+}
+
+impl<R> CommutativeRing for Integer<R> where R: EuclideanDomain + PartialOrd {}
+
+impl<R> IntegralDomain for Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.value.checked_div(&rhs.value).map(Self::new)
+    }
+}
+
+impl<R> EuclideanDomain for Integer<R>
+where
+    R: EuclideanDomain + PartialOrd,
+{
+    type Norm = R::Norm;
+
+    /// The Euclidean function of the integers is the absolute value.
+    fn norm(&self) -> Self::Norm {
+        self.value.norm()
+    }
+
+    /// Returns the quotient and the remainder of the Euclidean division, where the remainder is
+    /// always non-negative, unlike the truncating [`Div`] and [`Rem`].
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.value.div_rem(&rhs.value);
+        (Self::new(quotient), Self::new(remainder))
+    }
+}
+
+impl<R> CheckedRing for Integer<R>
+where
+    R: CheckedRing + EuclideanDomain + PartialOrd,
+{
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        Self::new(self.value.saturating_add(&rhs.value))
+    }
+
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        Self::new(self.value.saturating_sub(&rhs.value))
+    }
+
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        Self::new(self.value.saturating_mul(&rhs.value))
+    }
 }
 
 #[cfg(test)]
@@ -245,7 +315,7 @@ mod test {
 
     use crate::{
         identities::{One, Zero},
-        structures::{integers::Integer, Group, Ring},
+        structures::{integers::Integer, CheckedRing, Group, IntegralDomain, Ring},
     };
 
     #[test]
@@ -280,4 +350,22 @@ mod test {
         let a = Integer::<isize>::from_str("1234.5");
         assert!(a.is_ok());
     }
+
+    #[test]
+    fn checked_operations_should_detect_overflow() {
+        let max = Integer::<i32>::new(i32::MAX);
+        let two = Integer::<i32>::new(2);
+        pretty_assertions::assert_eq!(max.checked_add(&two), None);
+        pretty_assertions::assert_eq!(max.checked_mul(&two), None);
+        pretty_assertions::assert_eq!(Integer::new(i32::MIN).checked_sub(&Integer::one()), None);
+        pretty_assertions::assert_eq!(Integer::new(i32::MIN).checked_div(&Integer::new(-1)), None);
+        pretty_assertions::assert_eq!(max.checked_div(&Integer::zero()), None);
+        pretty_assertions::assert_eq!(max.checked_sub(&two), Some(Integer::new(i32::MAX - 2)));
+        pretty_assertions::assert_eq!(max.saturating_add(&two), max);
+        pretty_assertions::assert_eq!(
+            Integer::new(i32::MIN).saturating_mul(&two),
+            Integer::new(i32::MIN)
+        );
+        pretty_assertions::assert_eq!(Integer::new(-7).saturating_sub(&two), Integer::new(-9));
+    }
 }
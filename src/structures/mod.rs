@@ -1,6 +1,11 @@
+pub mod big_integers;
 pub mod complex;
 pub mod errors;
+pub mod gaussian_integers;
 pub mod integers;
+pub mod modular;
+pub mod polynomials;
+pub mod quaternions;
 pub mod rationals;
 pub mod reals;
 
@@ -24,7 +29,7 @@ use crate::{
 /// 1. **Closure**: For all _a_, _b_ in _G_, the result of the operation _a_ · _b_ is also in _G_.
 /// 2. **Associativity**: For all _a_, _b_, _c_ in _G_, the equation (_a_ · _b_) · _c_ = _a_ · (_b_ · _c_) holds.
 /// 3. **Identity element**: There exists an element _e_ in _G_ such that for every element _a_ in _G_, the equation
-/// _e_ · _a_ = _a_ · _e_ = _a_ holds. Such an element is unique and thus one speaks of **the** identity element.
+///    _e_ · _a_ = _a_ · _e_ = _a_ holds. Such an element is unique and thus one speaks of **the** identity element.
 /// 4. **Inverse element**: For each _a_ in _G_, there exists an element _b_ in _G_ such that _a_ · _b_ = _b_ · _a_ = _e_, where _e_ is the identity element.
 ///
 /// # Implementation
@@ -37,11 +42,11 @@ use crate::{
 ///
 /// ## Methods
 /// 1. The method [`Group::identity`] will return the identity element. It is unnecessary as it will be the same as the defined
-/// [`Zero`] element. But for the sake of maintaining the mathematical notation of the definition, it is written.
+///    [`Zero`] element. But for the sake of maintaining the mathematical notation of the definition, it is written.
 /// 2. The method [`Group::inverse`] will return the inverse element of the current element. Also might seem unneded because the
-/// trait [`Neg`] is already implemented, but it is necessary to maintain the mathematical notation of the definition.
+///    trait [`Neg`] is already implemented, but it is necessary to maintain the mathematical notation of the definition.
 /// 3. The method [`Group::op`] will return the result of the operation _·_ between the current element and the element passed as
-/// argument.
+///    argument.
 ///
 /// # Example
 /// Let's write the implementation of a group for the [`isize`] type.
@@ -132,19 +137,24 @@ impl_group_for_primitives!(isize, i8, i16, i32, i64, i128);
 /// ## Trait bounds
 ///
 /// 1. The trait [`Mul`] is used to define the binary operation _·_. It is usually associated with a product. The binary operation _+_ is
-/// implicitly defined by the trait [`Group`].
-/// 3. The trait [`Rem`] is used to define the behaviour of the division in a ring.
-/// 4. The trait [`Div`] is used to define the division in a ring.
-/// 5. The trait [`One`] is used to define the **multiplicative identity**. It is usually associated with the multiplicative identity.
-/// 6. All other traits are needed for the implementation of a generic numeric type.
+///    implicitly defined by the trait [`Group`].
+/// 2. The trait [`One`] is used to define the **multiplicative identity**. It is usually associated with the multiplicative identity.
+/// 3. All other traits are needed for the implementation of a generic numeric type.
+///
+/// A ring has no division in general. It is defined by [`IntegralDomain`], and the remainder by
+/// [`EuclideanDomain`].
 ///
 /// ## Methods
 ///
 /// 1. The method [`Ring::sum`] will return the result of the operation _+_ between the current element and the element passed as
-/// argument.
+///    argument.
 /// 2. The method [`Ring::mul`] will return the result of the operation _·_ between the current element and the element passed as
-/// argument.
+///    argument.
 /// 3. The method [`Ring::inverse_addition`] will return the **additive inverse** of the current element.
+/// 4. The methods [`Ring::checked_add`], [`Ring::checked_sub`] and [`Ring::checked_mul`] will return [`None`]
+///    whenever the result cannot be represented. Only bounded types, see [`CheckedRing`], can overflow, so by
+///    default they never fail. Matrix operations and determinants use them to return
+///    [`MatrixError::Overflow`](crate::matrix::MatrixError::Overflow) instead of wrapping or panicking.
 ///
 /// # Example
 ///
@@ -170,9 +180,7 @@ impl_group_for_primitives!(isize, i8, i16, i32, i64, i128);
 /// 2. [MathWorld](https://mathworld.wolfram.com/Ring.html)
 /// 3. [ProofWiki](https://proofwiki.org/wiki/Definition:Ring_(Abstract_Algebra))
 /// 4. [PlanetMath](https://planetmath.org/definitionofaring)
-pub trait Ring:
-    Group + Mul<Output = Self> + Rem<Output = Self> + One + Div<Output = Self> + AsF32
-{
+pub trait Ring: Group + Mul<Output = Self> + One + AsF32 {
     /// Will return the result of the operation _+_ between the current element and the element passed as
     /// argument.
     fn sum(&self, rhs: &Self) -> Self;
@@ -185,6 +193,21 @@ pub trait Ring:
     fn inverse_addition(&self) -> Self {
         Self::inverse(self)
     }
+
+    /// Will return the sum, or [`None`] if it overflows.
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() + rhs.clone())
+    }
+
+    /// Will return the difference, or [`None`] if it overflows.
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() - rhs.clone())
+    }
+
+    /// Will return the product, or [`None`] if it overflows.
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() * rhs.clone())
+    }
 }
 
 macro_rules! impl_ring_for_primitives {
@@ -202,12 +225,220 @@ macro_rules! impl_ring_for_primitives {
             fn inverse_addition(&self) -> Self {
                 -*self
             }
+
+            fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_add(*self, *rhs)
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *rhs)
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *rhs)
+            }
         })*
     };
 }
 
 impl_ring_for_primitives!(isize, i8, i16, i32, i64, i128);
 
+/// Marker trait for the elements of a commutative ring.
+///
+/// # Definition
+///
+/// A **commutative ring** is a [`Ring`] whose multiplication is commutative, i.e., _a_ · _b_ = _b_ · _a_
+/// for all _a_, _b_ in the ring.
+///
+/// # Implementation
+///
+/// The trait [`Ring`] does not assume commutativity, so there are rings like the
+/// [`Quaternion`](quaternions::Quaternion)s that are not commutative. Matrix multiplication keeps
+/// the order of the factors, so it works for any [`Ring`], but the algorithms that rely on
+/// commutativity, like determinants or characteristic polynomials, require this trait. The
+/// [`IntegralDomain`]s, and hence the [`Field`]s, are commutative rings.
+///
+/// ```compile_fail
+/// use std::str::FromStr;
+/// use math_rs::{matrix::square::SquareMatrix, structures::quaternions::Quaternion};
+///
+/// let matrix = SquareMatrix::<Quaternion>::from_str("{{i,j},{k,1}}").unwrap();
+/// let polynomial = matrix.characteristic_polynomial();
+/// ```
+///
+/// # References
+///
+/// 1. [Wikipedia](https://en.wikipedia.org/wiki/Commutative_ring)
+/// 2. [MathWorld](https://mathworld.wolfram.com/CommutativeRing.html)
+pub trait CommutativeRing: Ring {}
+
+macro_rules! impl_commutative_ring_for_primitives {
+    ($($t:ty),*) => {
+        $(impl CommutativeRing for $t {})*
+    };
+}
+
+impl_commutative_ring_for_primitives!(isize, i8, i16, i32, i64, i128);
+
+/// Defines overflow-aware arithmetic for a [`Ring`] whose elements have a bounded
+/// representation, such as the primitive integers.
+///
+/// # Implementation
+///
+/// ## Methods
+///
+/// 1. The checked operations [`Ring::checked_add`], [`Ring::checked_sub`], [`Ring::checked_mul`] and
+///    [`IntegralDomain::checked_div`] are defined for every ring, so that generic algorithms can use
+///    them. These types override them to return [`None`] whenever the result cannot be represented,
+///    instead of wrapping or panicking depending on the build profile.
+/// 2. The methods [`CheckedRing::saturating_add`], [`CheckedRing::saturating_sub`] and
+///    [`CheckedRing::saturating_mul`] will return the closest representable element whenever the
+///    result overflows.
+///
+/// Types without bounds, like [`BigInt`](big_integers::BigInt), never overflow.
+pub trait CheckedRing: Ring {
+    /// Will return the sum, saturated at the bounds of the type.
+    fn saturating_add(&self, rhs: &Self) -> Self;
+
+    /// Will return the difference, saturated at the bounds of the type.
+    fn saturating_sub(&self, rhs: &Self) -> Self;
+
+    /// Will return the product, saturated at the bounds of the type.
+    fn saturating_mul(&self, rhs: &Self) -> Self;
+}
+
+macro_rules! impl_checked_ring_for_primitives {
+    ($($t:ty),*) => {
+        $(impl CheckedRing for $t {
+            fn saturating_add(&self, rhs: &Self) -> Self {
+                <$t>::saturating_add(*self, *rhs)
+            }
+
+            fn saturating_sub(&self, rhs: &Self) -> Self {
+                <$t>::saturating_sub(*self, *rhs)
+            }
+
+            fn saturating_mul(&self, rhs: &Self) -> Self {
+                <$t>::saturating_mul(*self, *rhs)
+            }
+        })*
+    };
+}
+
+impl_checked_ring_for_primitives!(isize, i8, i16, i32, i64, i128);
+
+/// Defines the necessary behavior of an element of an integral domain.
+///
+/// # Definition
+///
+/// An **integral domain** is a nonzero commutative ring _D_ with no zero divisors, i.e., such that
+/// _a_ · _b_ = 0 implies _a_ = 0 or _b_ = 0 for all _a_, _b_ in _D_. Hence, whenever _b_ ≠ 0 divides
+/// _a_, the element _q_ such that _a_ = _b_ · _q_ is unique.
+///
+/// # Implementation
+///
+/// ## Trait bounds
+///
+/// 1. The trait [`CommutativeRing`] is used to englobe the necessary traits for the implementation of an integral domain.
+/// 2. The trait [`Div`] is used to define the **exact division**, i.e., `a / b` is only required to be the
+///    element _q_ such that _a_ = _b_ · _q_ when _b_ divides _a_. This is what fraction-free algorithms,
+///    like the Bareiss algorithm, need.
+///
+/// ## Methods
+///
+/// 1. The method [`IntegralDomain::checked_div`] will return [`None`] whenever the quotient cannot be
+///    represented, as [`Ring::checked_mul`] does.
+///
+/// ## Constants
+///
+/// 1. The constant [`IntegralDomain::IS_FIELD`] tells the algorithms that divide by arbitrary elements,
+///    like the Gaussian elimination, whether `/` is the division of a [`Field`].
+///
+/// # References
+///
+/// 1. [Wikipedia](https://en.wikipedia.org/wiki/Integral_domain)
+/// 2. [MathWorld](https://mathworld.wolfram.com/IntegralDomain.html)
+pub trait IntegralDomain: CommutativeRing + Div<Output = Self> {
+    /// Whether the domain is a [`Field`], so that `/` divides by every non-zero element. Every
+    /// [`Field`] must set it to `true`.
+    const IS_FIELD: bool = false;
+
+    /// Will return the quotient, or [`None`] if it overflows. The bounded types of [`CheckedRing`]
+    /// also return [`None`] if `rhs` is zero.
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        Some(self.clone() / rhs.clone())
+    }
+}
+
+macro_rules! impl_integral_domain_for_primitives {
+    ($($t:ty),*) => {
+        $(impl IntegralDomain for $t {
+            fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_div(*self, *rhs)
+            }
+        })*
+    };
+}
+
+impl_integral_domain_for_primitives!(isize, i8, i16, i32, i64, i128);
+
+/// Defines the necessary behavior of an element of a Euclidean domain.
+///
+/// # Definition
+///
+/// A **Euclidean domain** is an integral domain _E_ equipped with a function _N_ from the non-zero
+/// elements of _E_ to a well-ordered set, called the **Euclidean function** (or norm), such that for
+/// every _a_ and every _b_ ≠ 0 in _E_ there exist _q_ and _r_ in _E_ with _a_ = _b_ · _q_ + _r_ and
+/// either _r_ = 0 or _N_(_r_) < _N_(_b_).
+///
+/// # Implementation
+///
+/// ## Methods
+///
+/// 1. The method [`EuclideanDomain::norm`] will return the **Euclidean function** of the current element,
+///    e.g. the absolute value of an integer or the degree of a polynomial.
+/// 2. The method [`EuclideanDomain::div_rem`] will return the quotient _q_ and the remainder _r_ of the
+///    division by the element passed as argument. For integers, the remainder is always non-negative.
+///
+/// The trait [`Rem`] is also required, as the remainder of the division defined by the type itself,
+/// which for the primitive integers truncates towards zero and might be negative.
+///
+/// The algorithms of [`euclid`](crate::arithmetics::euclid) are written once for every Euclidean domain.
+///
+/// # References
+///
+/// 1. [Wikipedia](https://en.wikipedia.org/wiki/Euclidean_domain)
+/// 2. [MathWorld](https://mathworld.wolfram.com/EuclideanDomain.html)
+pub trait EuclideanDomain: IntegralDomain + Rem<Output = Self> {
+    /// Type of the values of the Euclidean function.
+    type Norm: PartialOrd;
+
+    /// Will return the value of the **Euclidean function** of the current element.
+    fn norm(&self) -> Self::Norm;
+
+    /// Will return the quotient and the remainder of the division by `rhs`, such that
+    /// `self = rhs · quotient + remainder` and the norm of the remainder is smaller than the one of `rhs`.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self);
+}
+
+macro_rules! impl_euclidean_domain_for_primitives {
+    ($($t:ty),*) => {
+        $(impl EuclideanDomain for $t {
+            type Norm = u128;
+
+            fn norm(&self) -> Self::Norm {
+                self.unsigned_abs() as u128
+            }
+
+            fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+                (self.div_euclid(*rhs), self.rem_euclid(*rhs))
+            }
+        })*
+    };
+}
+
+impl_euclidean_domain_for_primitives!(isize, i8, i16, i32, i64, i128);
+
 /// Defines the necessary behavior of an element of a field.
 ///
 /// # Definition
@@ -231,8 +462,9 @@ impl_ring_for_primitives!(isize, i8, i16, i32, i64, i128);
 ///
 /// ## Trait bounds
 ///
-/// 1. The trait [`Ring`] is used to englobe the necessary traits for the implementation of a field.
-/// 2. The trait [`Div`] is used to define the division in a field.
+/// 1. The trait [`IntegralDomain`] is used to englobe the necessary traits for the implementation of a field.
+///    In a field the division by any non-zero element is defined, so [`IntegralDomain::IS_FIELD`] must be
+///    `true`.
 ///
 /// ## Methods
 ///
@@ -241,6 +473,6 @@ impl_ring_for_primitives!(isize, i8, i16, i32, i64, i128);
 /// # Example
 ///
 /// To be done...
-pub trait Field: Ring + Div {
+pub trait Field: IntegralDomain {
     fn inverse_multiplication(&self) -> Self;
 }
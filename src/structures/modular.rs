@@ -0,0 +1,397 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use super::{
+    errors::StructureError, integers::Integer, CommutativeRing, Field, Group, IntegralDomain, Ring,
+};
+use crate::{
+    arithmetics::euclid,
+    equality::{Equals, Tolerance},
+    identities::{One, Zero},
+    num_types::{AsF32, FromF32},
+    traits::Abs,
+};
+
+/// Returns whether `n` is a prime number, by trial division. It is evaluated at compile time
+/// to check that a [`PrimeField`] has a prime modulus.
+const fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut divisor = 2;
+    while divisor <= n / divisor {
+        if n.is_multiple_of(divisor) {
+            return false;
+        }
+        divisor += 1;
+    }
+    true
+}
+
+/// Representation of an element of the ring of integers modulo `N`, _Z/NZ_.
+///
+/// It is always stored as its representative in _[0, N)_. The ring has zero divisors when `N` is
+/// composite, e.g. _2 · 3 = 0_ modulo _6_, so `Modular<N>` is only a [`CommutativeRing`]. The
+/// units can still be inverted with [`Modular::checked_inverse`], but there is no division and
+/// the algorithms that divide, like determinants, are not available:
+///
+/// ```compile_fail
+/// use std::str::FromStr;
+/// use math_rs::{
+///     matrix::square::{determinant::DeterminantMethod, SquareMatrix},
+///     structures::modular::Modular,
+/// };
+///
+/// let matrix = SquareMatrix::<Modular<6>>::from_str("{{2,3},{3,2}}").unwrap();
+/// let determinant = matrix.determinant(DeterminantMethod::GaussianElimination, 0.);
+/// ```
+///
+/// When `N` is prime, _Z/NZ_ is the finite field _GF(N)_, which is the type [`PrimeField`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub struct Modular<const N: u64, const PRIME: bool = false> {
+    value: u64,
+}
+
+/// Representation of an element of the finite field _GF(P)_, i.e., the integers modulo a prime
+/// `P`. It implements [`Field`], so that determinants, ranks or inverses of matrices over _GF(P)_
+/// can be computed, e.g. with `Matrix<PrimeField<7>>`.
+///
+/// The primality of `P` is checked at compile time whenever an element is built, so a type like
+/// `PrimeField<6>` cannot be used.
+pub type PrimeField<const P: u64> = Modular<P, true>;
+
+impl<const N: u64, const PRIME: bool> Modular<N, PRIME> {
+    /// Returns the class of `value` modulo `N`, which might be negative.
+    pub fn new(value: i128) -> Self {
+        const { assert!(N > 0, "The modulus must be positive") };
+        const {
+            assert!(
+                !PRIME || is_prime(N),
+                "The modulus of a field must be prime"
+            )
+        };
+        Self {
+            value: value.rem_euclid(N as i128) as u64,
+        }
+    }
+
+    /// Returns the representative of the class, in _[0, N)_.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus `N`.
+    pub fn modulus(&self) -> u64 {
+        N
+    }
+
+    /// Returns the multiplicative inverse, computed with [`euclid::extended_gcd`], or [`None`]
+    /// if the element is not coprime with `N`.
+    pub fn checked_inverse(&self) -> Option<Self> {
+        let (gcd, x, _) =
+            euclid::extended_gcd(&Integer::new(self.value as i128), &Integer::new(N as i128));
+        match *gcd.value() {
+            1 => Some(Self::new(*x.value())),
+            _ => None,
+        }
+    }
+
+    /// Returns `self` raised to the power of `exponent`, by binary exponentiation.
+    pub fn pow(&self, mut exponent: u64) -> Self {
+        let mut base = *self;
+        let mut result = Self::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        result
+    }
+}
+
+impl<const N: u64, const PRIME: bool> From<i128> for Modular<N, PRIME> {
+    fn from(value: i128) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Display for Modular<N, PRIME> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const N: u64, const PRIME: bool> FromStr for Modular<N, PRIME> {
+    type Err = StructureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(i128::from_str(s)?))
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Add for Modular<N, PRIME> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value as i128 + rhs.value as i128)
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Neg for Modular<N, PRIME> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-(self.value as i128))
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Sub for Modular<N, PRIME> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value as i128 - rhs.value as i128)
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Mul for Modular<N, PRIME> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            value: ((self.value as u128 * rhs.value as u128) % N as u128) as u64,
+        }
+    }
+}
+
+impl<const P: u64> Div for PrimeField<P> {
+    type Output = Self;
+
+    /// Multiplies by the inverse of `rhs`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self::Output {
+        Ring::mul(&self, &rhs.inverse_multiplication())
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Zero for Modular<N, PRIME> {
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    fn is_zero<T: Tolerance>(&self, _: T) -> bool {
+        self.value == 0
+    }
+}
+
+impl<const N: u64, const PRIME: bool> One for Modular<N, PRIME> {
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    fn is_one<T: Tolerance>(&self, _: T) -> bool {
+        self.value == 1 % N
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Equals for Modular<N, PRIME> {
+    fn equals<T: Tolerance>(&self, rhs: &Self, _: T) -> bool {
+        self.value == rhs.value
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Abs for Modular<N, PRIME> {
+    type Output = Self;
+
+    /// There is no absolute value in _Z/NZ_, so the element itself is returned.
+    fn abs_value(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl<const N: u64, const PRIME: bool> AsF32 for Modular<N, PRIME> {
+    fn as_f32(&self) -> f32 {
+        self.value as f32
+    }
+}
+
+impl<const N: u64, const PRIME: bool> FromF32 for Modular<N, PRIME> {
+    fn from_f32(value: f32, _: f32) -> Self {
+        Self::new(value.round() as i128)
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Group for Modular<N, PRIME> {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        -*self
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+}
+
+impl<const N: u64, const PRIME: bool> Ring for Modular<N, PRIME> {
+    fn sum(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+}
+
+impl<const N: u64, const PRIME: bool> CommutativeRing for Modular<N, PRIME> {}
+
+impl<const P: u64> IntegralDomain for PrimeField<P> {
+    const IS_FIELD: bool = true;
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        rhs.checked_inverse().map(|inverse| *self * inverse)
+    }
+}
+
+impl<const P: u64> Field for PrimeField<P> {
+    /// Returns the multiplicative inverse, computed with [`Modular::checked_inverse`].
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the element is zero.
+    fn inverse_multiplication(&self) -> Self {
+        match self.checked_inverse() {
+            Some(inverse) => inverse,
+            None => panic!("Zero has no multiplicative inverse"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::{is_prime, Modular, PrimeField};
+    use crate::{
+        equality::Equals,
+        identities::{One, Zero},
+        matrix::{
+            generic::Matrix,
+            square::{determinant::DeterminantMethod, SquareMatrix},
+            AsMatrix, MatrixError,
+        },
+        structures::Field,
+    };
+
+    #[test]
+    fn modular_arithmetic_should_not_fail() {
+        struct TestCase {
+            id: &'static str,
+            computed: PrimeField<7>,
+            expected: i128,
+        }
+        let (a, b) = (PrimeField::<7>::new(5), PrimeField::<7>::new(-4));
+        vec![
+            TestCase {
+                id: "Negative representative",
+                computed: b,
+                expected: 3,
+            },
+            TestCase {
+                id: "Sum",
+                computed: a + b,
+                expected: 1,
+            },
+            TestCase {
+                id: "Subtraction",
+                computed: b - a,
+                expected: 5,
+            },
+            TestCase {
+                id: "Product",
+                computed: a * b,
+                expected: 1,
+            },
+            TestCase {
+                id: "Division",
+                computed: Modular::one() / a,
+                expected: 3,
+            },
+            TestCase {
+                id: "Fermat's little theorem",
+                computed: a.pow(6),
+                expected: 1,
+            },
+            TestCase {
+                id: "Parsed",
+                computed: Modular::from_str("-15").unwrap(),
+                expected: 6,
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            pretty_assertions::assert_eq!(
+                test.computed,
+                Modular::new(test.expected),
+                "Test case: {} failed",
+                test.id
+            )
+        });
+        let large = Modular::<{ u64::MAX - 58 }>::new(-1);
+        pretty_assertions::assert_eq!(large * large, Modular::one());
+    }
+
+    #[test]
+    fn inverses_should_exist_only_for_units() {
+        for value in 1..13 {
+            let element = PrimeField::<13>::new(value);
+            assert!((element * element.inverse_multiplication()).is_one(0.));
+        }
+        pretty_assertions::assert_eq!(
+            Modular::<12>::new(5).checked_inverse(),
+            Some(Modular::new(5))
+        );
+        pretty_assertions::assert_eq!(Modular::<12>::new(8).checked_inverse(), None);
+        pretty_assertions::assert_eq!(Modular::<12>::zero().checked_inverse(), None);
+        assert!(is_prime(2) && is_prime(13) && is_prime(1_000_000_007));
+        assert!(!is_prime(1) && !is_prime(12) && !is_prime(91));
+    }
+
+    #[test]
+    fn matrices_over_finite_fields() {
+        let matrix = SquareMatrix::<PrimeField<7>>::from_str("{{1,2,3},{4,5,6},{7,8,10}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.determinant(DeterminantMethod::GaussianElimination, 0.),
+            Ok(PrimeField::new(-3))
+        );
+        pretty_assertions::assert_eq!(
+            matrix.determinant(DeterminantMethod::LaplaceExpansion, 0.),
+            Ok(PrimeField::new(-3))
+        );
+        let inverse = matrix.inverse_gauss_jordan(0.).unwrap();
+        assert!((matrix * inverse)
+            .unwrap()
+            .equals(&SquareMatrix::identity(3), 0.));
+
+        // The determinant is 0 modulo 3, so the matrix is singular over GF(3).
+        let matrix = SquareMatrix::<PrimeField<3>>::from_str("{{1,2,3},{4,5,6},{7,8,10}}").unwrap();
+        pretty_assertions::assert_eq!(
+            matrix.inverse_gauss_jordan(0.),
+            Err(MatrixError::SingularMatrix)
+        );
+        pretty_assertions::assert_eq!(Matrix::from(matrix).rank(0.), Ok(2));
+
+        let matrix = Matrix::<PrimeField<2>>::from_str("{{1,1,0},{0,1,1},{1,0,1}}").unwrap();
+        pretty_assertions::assert_eq!(matrix.rank(0.), Ok(2));
+        pretty_assertions::assert_eq!(matrix.null_space_basis(0.).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,571 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use super::{
+    errors::StructureError, CommutativeRing, EuclideanDomain, Field, Group, IntegralDomain, Ring,
+};
+use crate::{
+    arithmetics::euclid,
+    equality::{Equals, Tolerance},
+    identities::{One, Zero},
+    num_types::AsF32,
+    traits::Abs,
+};
+
+/// Tolerance used to drop the zero coefficients of the highest degrees. As it is the smallest
+/// positive [`f32`], only the exact zeros are dropped, even for [`Real`](super::reals::Real)
+/// coefficients. See [`Polynomial::trimmed`] to drop them with a custom tolerance.
+const EXACT: f32 = f32::MIN_POSITIVE;
+
+/// Representation of a univariate polynomial _a<sub>0</sub> + a<sub>1</sub>x + ... +
+/// a<sub>n</sub>x<sup>n</sup>_ with coefficients in a [`Ring`].
+///
+/// The coefficients are stored from the lowest to the highest degree, and the leading one is never
+/// zero, so the zero polynomial has no coefficients.
+///
+/// Polynomials are a [`Ring`] themselves, so they can be the elements of a
+/// [`Matrix`](crate::matrix::generic::Matrix). When the coefficients are a [`Field`], they are also an
+/// [`EuclideanDomain`], whose Euclidean function is the degree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<R: Ring> {
+    coefficients: Vec<R>,
+}
+
+impl<R: Ring> Polynomial<R> {
+    /// Returns the polynomial with the given `coefficients`, from the lowest to the highest degree.
+    pub fn new(coefficients: Vec<R>) -> Self {
+        Self { coefficients }.trimmed(EXACT)
+    }
+
+    /// Returns the polynomial _c·x<sup>degree</sup>_.
+    pub fn monomial(coefficient: R, degree: usize) -> Self {
+        let mut coefficients = vec![R::zero(); degree];
+        coefficients.push(coefficient);
+        Self::new(coefficients)
+    }
+
+    /// Returns the polynomial _x_.
+    pub fn x() -> Self {
+        Self::monomial(R::one(), 1)
+    }
+
+    /// Returns the coefficients, from the lowest to the highest degree.
+    pub fn coefficients(&self) -> &[R] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficient of _x<sup>power</sup>_, which is zero beyond the degree.
+    pub fn coefficient(&self, power: usize) -> R {
+        self.coefficients
+            .get(power)
+            .cloned()
+            .unwrap_or_else(R::zero)
+    }
+
+    /// Returns the degree, or [`None`] for the zero polynomial, whose degree is _-∞_.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Returns the coefficient of the highest degree, or zero for the zero polynomial.
+    pub fn leading_coefficient(&self) -> R {
+        self.coefficients.last().cloned().unwrap_or_else(R::zero)
+    }
+
+    /// Returns the polynomial without the coefficients of the highest degrees that are zero within
+    /// the `tolerance`.
+    pub fn trimmed(mut self, tolerance: f32) -> Self {
+        while self
+            .coefficients
+            .last()
+            .is_some_and(|coefficient| coefficient.is_zero(tolerance))
+        {
+            self.coefficients.pop();
+        }
+        self
+    }
+
+    /// Returns the value of the polynomial at `x`, computed with
+    /// [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    ///
+    /// ## Example
+    ///
+    /// If you have the polynomial `+1x^2-3x+2` and `x = 3`, then it is computed as
+    /// ```txt
+    /// ((1 · 3) - 3) · 3 + 2 = 2
+    /// ```
+    pub fn evaluate(&self, x: &R) -> R {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(R::zero(), |value, coefficient| {
+                value * x.clone() + coefficient.clone()
+            })
+    }
+
+    /// Returns the value of the polynomial at `x`, as [`Polynomial::evaluate`] does, or [`None`]
+    /// if some intermediate result overflows. See [`Ring::checked_mul`].
+    pub fn checked_evaluate(&self, x: &R) -> Option<R> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(R::zero(), |value, coefficient| {
+                value.checked_mul(x)?.checked_add(coefficient)
+            })
+    }
+
+    /// Returns the derivative of the polynomial.
+    pub fn derivative(&self) -> Self {
+        let mut factor = R::zero();
+        Self::new(
+            self.coefficients
+                .iter()
+                .skip(1)
+                .map(|coefficient| {
+                    factor = factor.clone() + R::one();
+                    coefficient.clone() * factor.clone()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<R: Field> Polynomial<R> {
+    /// Returns the polynomial divided by its leading coefficient, or the zero polynomial.
+    pub fn monic(&self) -> Self {
+        if self.coefficients.is_empty() {
+            return self.clone();
+        }
+        let inverse = self.leading_coefficient().inverse_multiplication();
+        Self::new(
+            self.coefficients
+                .iter()
+                .map(|coefficient| coefficient.clone() * inverse.clone())
+                .collect(),
+        )
+    }
+
+    /// Returns the monic greatest common divisor of `self` and `rhs`, computed with
+    /// [`euclid::gcd`], or the zero polynomial if both are zero.
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        euclid::gcd(self, rhs).monic()
+    }
+}
+
+/// Returns the coefficient with an explicit sign, wrapped in parentheses if it has more signs
+/// inside, as [`Complex`](super::complex::Complex) numbers do.
+fn signed<R: Ring>(coefficient: &R) -> String {
+    let representation = coefficient.to_string();
+    let unsigned = representation
+        .strip_prefix(['+', '-'])
+        .unwrap_or(&representation);
+    if unsigned.contains(['+', '-']) {
+        format!("+({})", representation)
+    } else if representation.starts_with(['+', '-']) {
+        representation
+    } else {
+        format!("+{}", representation)
+    }
+}
+
+impl<R: Ring> Display for Polynomial<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.coefficients.is_empty() {
+            return write!(f, "{}", signed(&R::zero()));
+        }
+        for (power, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero(EXACT) {
+                continue;
+            }
+            match power {
+                0 => write!(f, "{}", signed(coefficient))?,
+                1 => write!(f, "{}x", signed(coefficient))?,
+                _ => write!(f, "{}x^{}", signed(coefficient), power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses a term like `-3x^2`, `+x`, `(1+2i)x` or `+2`, returning its power and its coefficient.
+fn parse_term<R: Ring>(term: &str) -> Result<(usize, R), StructureError> {
+    let error = || StructureError::ParseError(format!("Invalid polynomial term: {}", term));
+    let (negative, body) = match term.strip_prefix('-') {
+        Some(body) => (true, body),
+        None => (false, term.strip_prefix('+').unwrap_or(term)),
+    };
+    let (coefficient, power) = match body.rfind('x') {
+        Some(index) => {
+            let exponent = &body[index + 1..];
+            let power = match exponent.strip_prefix('^') {
+                Some(power) => power.parse::<usize>()?,
+                None if exponent.is_empty() => 1,
+                None => return Err(error()),
+            };
+            (
+                body[..index].strip_suffix('*').unwrap_or(&body[..index]),
+                power,
+            )
+        }
+        None => (body, 0),
+    };
+    let coefficient = coefficient
+        .strip_prefix('(')
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(coefficient);
+    let value = match coefficient {
+        "" if power == 0 => return Err(error()),
+        "" => R::one(),
+        coefficient => R::from_str(coefficient).map_err(|_| error())?,
+    };
+    Ok((power, if negative { -value } else { value }))
+}
+
+impl<R: Ring> FromStr for Polynomial<R> {
+    type Err = StructureError;
+
+    /// Parses polynomials in the format of [`Display`], like `+1x^2-3x+2`, but also with implicit
+    /// coefficients and signs, whitespaces or `*`, like `x^2 - 3*x + 2`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.split_whitespace().collect::<String>();
+        let mut terms = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, character) in input.char_indices() {
+            match character {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '+' | '-'
+                    if depth == 0 && i > start && !input[..i].ends_with(['e', 'E', '^', '/']) =>
+                {
+                    terms.push(&input[start..i]);
+                    start = i;
+                }
+                _ => {}
+            }
+        }
+        terms.push(&input[start..]);
+
+        let mut coefficients = Vec::new();
+        for term in terms {
+            let (power, value) = parse_term::<R>(term)?;
+            if coefficients.len() <= power {
+                coefficients.resize(power + 1, R::zero());
+            }
+            coefficients[power] = coefficients[power].clone() + value;
+        }
+        Ok(Self::new(coefficients))
+    }
+}
+
+impl<R: Ring> Add for Polynomial<R> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let length = self.coefficients.len().max(rhs.coefficients.len());
+        Self::new(
+            (0..length)
+                .map(|power| self.coefficient(power) + rhs.coefficient(power))
+                .collect(),
+        )
+    }
+}
+
+impl<R: Ring> Neg for Polynomial<R> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.coefficients.into_iter().map(|c| -c).collect())
+    }
+}
+
+impl<R: Ring> Sub for Polynomial<R> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<R: Ring> Mul for Polynomial<R> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.coefficients.is_empty() || rhs.coefficients.is_empty() {
+            return Self::zero();
+        }
+        let mut coefficients =
+            vec![R::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] = coefficients[i + j].clone() + a.clone() * b.clone();
+            }
+        }
+        Self::new(coefficients)
+    }
+}
+
+impl<R: Field> Div for Polynomial<R> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<R: Field> Rem for Polynomial<R> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<R: Ring> Zero for Polynomial<R> {
+    fn zero() -> Self {
+        Self {
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.coefficients
+            .iter()
+            .all(|coefficient| coefficient.is_zero(tolerance))
+    }
+}
+
+impl<R: Ring> One for Polynomial<R> {
+    fn one() -> Self {
+        Self::new(vec![R::one()])
+    }
+
+    fn is_one<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.equals(&Self::one(), tolerance)
+    }
+}
+
+impl<R: Ring> Equals for Polynomial<R> {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        let length = self.coefficients.len().max(rhs.coefficients.len());
+        (0..length).all(|power| {
+            self.coefficient(power)
+                .equals(&rhs.coefficient(power), tolerance)
+        })
+    }
+}
+
+impl<R: Ring + PartialOrd> PartialOrd for Polynomial<R> {
+    /// Polynomials are ordered by degree, and then by their coefficients from the highest degree.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.coefficients.len().cmp(&other.coefficients.len()) {
+            Ordering::Equal => self
+                .coefficients
+                .iter()
+                .rev()
+                .partial_cmp(other.coefficients.iter().rev()),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl<R: Ring> Abs for Polynomial<R> {
+    type Output = Self;
+
+    /// There is no absolute value of a polynomial, so the polynomial itself is returned.
+    fn abs_value(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+impl<R: Ring> AsF32 for Polynomial<R> {
+    /// Returns the value of the polynomial at zero, i.e., its constant coefficient.
+    fn as_f32(&self) -> f32 {
+        self.coefficient(0).as_f32()
+    }
+}
+
+impl<R: Ring> Group for Polynomial<R> {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        -self.clone()
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+}
+
+impl<R: Ring> Ring for Polynomial<R> {
+    fn sum(&self, rhs: &Self) -> Self {
+        self.clone() + rhs.clone()
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        self.clone() * rhs.clone()
+    }
+}
+
+impl<R: CommutativeRing> CommutativeRing for Polynomial<R> {}
+
+impl<R: Field> IntegralDomain for Polynomial<R> {}
+
+impl<R: Field> EuclideanDomain for Polynomial<R> {
+    type Norm = Option<usize>;
+
+    /// The Euclidean function of the polynomials is the degree.
+    fn norm(&self) -> Self::Norm {
+        self.degree()
+    }
+
+    /// Returns the quotient and the remainder of the polynomial long division.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `rhs` is the zero polynomial.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let Some(degree) = rhs.degree() else {
+            panic!("attempt to divide by the zero polynomial");
+        };
+        if self.coefficients.len() <= degree {
+            return (Self::zero(), self.clone());
+        }
+        let inverse = rhs.leading_coefficient().inverse_multiplication();
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![R::zero(); remainder.len() - degree];
+        for k in (0..quotient.len()).rev() {
+            let factor = remainder[k + degree].clone() * inverse.clone();
+            for (j, coefficient) in rhs.coefficients.iter().enumerate() {
+                remainder[k + j] = remainder[k + j].clone() - factor.clone() * coefficient.clone();
+            }
+            remainder[k + degree] = R::zero();
+            quotient[k] = factor;
+        }
+        remainder.truncate(degree);
+        (Self::new(quotient), Self::new(remainder))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use super::Polynomial;
+    use crate::{
+        equality::Equals,
+        matrix::{
+            generic::Matrix,
+            square::{determinant::DeterminantMethod, SquareMatrix},
+        },
+        structures::{integers::Integer, rationals::Rational, reals::Real, EuclideanDomain},
+    };
+
+    fn rational(coefficients: &[i64]) -> Polynomial<Rational<i64>> {
+        Polynomial::new(coefficients.iter().map(|c| Rational::from(*c)).collect())
+    }
+
+    #[test]
+    fn display_and_parse_polynomials() {
+        struct TestCase<'a> {
+            id: &'a str,
+            input: &'a str,
+            expected: &'a str,
+        }
+        vec![
+            TestCase {
+                id: "Canonical format",
+                input: "+1x^2-3x+2",
+                expected: "+1x^2-3x+2",
+            },
+            TestCase {
+                id: "Implicit coefficients",
+                input: "x^3 - x + 0.5",
+                expected: "+1x^3-1x+0.5",
+            },
+            TestCase {
+                id: "Unordered and repeated terms",
+                input: "2 + 3*x - x + x^2 - x^2",
+                expected: "+2x+2",
+            },
+            TestCase {
+                id: "Zero polynomial",
+                input: "x - x",
+                expected: "+0",
+            },
+        ]
+        .into_iter()
+        .for_each(|test| {
+            let polynomial = Polynomial::<Real>::from_str(test.input).unwrap();
+            pretty_assertions::assert_eq!(
+                polynomial.to_string(),
+                test.expected,
+                "Test case: {} failed",
+                test.id
+            );
+            pretty_assertions::assert_eq!(
+                Polynomial::<Real>::from_str(&polynomial.to_string()).unwrap(),
+                polynomial
+            );
+        });
+        pretty_assertions::assert_eq!(
+            Polynomial::<Rational<i64>>::from_str("1/2x^2 - 3/4").unwrap(),
+            Polynomial::new(vec![
+                Rational::from_str("-3/4").unwrap(),
+                Rational::from(0),
+                Rational::from_str("1/2").unwrap()
+            ])
+        );
+        assert!(Polynomial::<Real>::from_str("2y").is_err());
+        assert!(Polynomial::<Real>::from_str("x^").is_err());
+        assert!(Polynomial::<Real>::from_str("").is_err());
+    }
+
+    #[test]
+    fn polynomial_arithmetic() {
+        let p = Polynomial::<Integer<i32>>::from_str("x^2 - 3x + 2").unwrap();
+        let q = Polynomial::<Integer<i32>>::from_str("x - 1").unwrap();
+        pretty_assertions::assert_eq!(p.degree(), Some(2));
+        pretty_assertions::assert_eq!(Polynomial::<Integer<i32>>::new(vec![]).degree(), None);
+        pretty_assertions::assert_eq!(p.leading_coefficient(), Integer::new(1));
+        pretty_assertions::assert_eq!(p.evaluate(&Integer::new(3)), Integer::new(2));
+        pretty_assertions::assert_eq!(p.derivative().to_string(), "+2x-3");
+        pretty_assertions::assert_eq!((p.clone() + q.clone()).to_string(), "+1x^2-2x+1");
+        pretty_assertions::assert_eq!((p.clone() - p.clone()).degree(), None);
+        pretty_assertions::assert_eq!((p * q).to_string(), "+1x^3-4x^2+5x-2");
+    }
+
+    #[test]
+    fn division_and_gcd_over_fields() {
+        let p = rational(&[-4, 0, -2, 1]);
+        let q = rational(&[-3, 1]);
+        let (quotient, remainder) = p.div_rem(&q);
+        pretty_assertions::assert_eq!(quotient, rational(&[3, 1, 1]));
+        pretty_assertions::assert_eq!(remainder, rational(&[5]));
+        assert!((quotient * q + remainder).equals(&p, 0.));
+
+        // (x - 1)(x - 2) and (x - 1)(x + 3) share the factor x - 1.
+        let a = rational(&[2, -3, 1]) * rational(&[4]);
+        let b = rational(&[-3, 2, 1]);
+        pretty_assertions::assert_eq!(a.gcd(&b), rational(&[-1, 1]));
+        pretty_assertions::assert_eq!(rational(&[1, 1]).gcd(&rational(&[1, -1])), rational(&[1]));
+    }
+
+    #[test]
+    fn matrices_of_polynomials() {
+        let matrix = Matrix::<Polynomial<Rational<i64>>>::from_str("{{2-x,1},{1,2-x}}").unwrap();
+        let square = SquareMatrix::try_from(matrix.clone()).unwrap();
+        pretty_assertions::assert_eq!(
+            square.determinant(DeterminantMethod::BareissAlgorithm, 0.),
+            Ok(rational(&[3, -4, 1]))
+        );
+        let product = (matrix.clone() * matrix).unwrap();
+        pretty_assertions::assert_eq!(product[(0, 1)], rational(&[4, -2]));
+    }
+}
@@ -0,0 +1,454 @@
+use std::{
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+use super::{errors::StructureError, reals::Real, Group, Ring};
+use crate::{
+    equality::{Equals, Tolerance},
+    identities::{One, Zero},
+    num_types::AsF32,
+    traits::Abs,
+};
+
+/// Representation of a [quaternion](https://en.wikipedia.org/wiki/Quaternion)
+/// _w + xi + yj + zk_, where _i² = j² = k² = ijk = -1_.
+///
+/// The quaternions are a division ring, i.e., every non-zero quaternion has an inverse, but
+/// the multiplication is not commutative: _ij = k_ but _ji = -k_. Hence, they implement
+/// [`Ring`] but not [`CommutativeRing`](super::CommutativeRing), and there is no [`Div`](std::ops::Div),
+/// as _pq<sup>-1</sup>_ and _q<sup>-1</sup>p_ are different.
+///
+/// The unit quaternions represent the rotations of the space, see [`Quaternion::rotate`].
+///
+/// As for [`Complex`](super::complex::Complex), the order compares the norms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quaternion {
+    w: Real,
+    x: Real,
+    y: Real,
+    z: Real,
+}
+
+impl Quaternion {
+    pub fn new(w: Real, x: Real, y: Real, z: Real) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /// Returns the quaternion _i_.
+    pub fn i() -> Self {
+        Self::from((0., 1., 0., 0.))
+    }
+
+    /// Returns the quaternion _j_.
+    pub fn j() -> Self {
+        Self::from((0., 0., 1., 0.))
+    }
+
+    /// Returns the quaternion _k_.
+    pub fn k() -> Self {
+        Self::from((0., 0., 0., 1.))
+    }
+
+    /// Returns the real part _w_.
+    pub fn scalar(&self) -> Real {
+        self.w
+    }
+
+    /// Returns the vector part _(x, y, z)_.
+    pub fn vector(&self) -> [Real; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    /// Returns the dot product of the components, as vectors of dimension 4.
+    pub fn dot(&self, other: &Self) -> Real {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Returns the norm _√(w² + x² + y² + z²)_, which is multiplicative.
+    pub fn norm(&self) -> Real {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns the unit quaternion with the same direction.
+    pub fn normalized(&self) -> Self {
+        self.scaled(Real::one() / self.norm())
+    }
+
+    /// Returns the multiplicative inverse _q<sup>*</sup>/|q|²_, which is both a left and a right
+    /// inverse.
+    pub fn inverse_multiplication(&self) -> Self {
+        self.conjugate().scaled(Real::one() / self.dot(self))
+    }
+
+    fn scaled(&self, factor: Real) -> Self {
+        Self::new(
+            self.w * factor,
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+        )
+    }
+
+    /// Returns the unit quaternion _cos(θ/2) + sin(θ/2)(xi + yj + zk)_ of the rotation of
+    /// `angle` radians around the `axis`, counterclockwise when the axis points to the viewer.
+    /// The axis does not need to be normalized.
+    pub fn from_axis_angle(axis: [Real; 3], angle: Real) -> Self {
+        let half = angle / Real::new(2.);
+        let axis = Self::new(Real::zero(), axis[0], axis[1], axis[2]).normalized();
+        Self::new(half.cos(), Real::zero(), Real::zero(), Real::zero()) + axis.scaled(half.sin())
+    }
+
+    /// Returns the `vector` rotated by the quaternion, i.e., the vector part of
+    /// _qvq<sup>-1</sup>_, where _v_ is the vector as a pure quaternion. It is a rotation for any
+    /// non-zero quaternion, as the norm cancels out.
+    ///
+    /// ## Example
+    ///
+    /// If the quaternion is the rotation of _π/2_ around the _z_ axis, _(√2 + √2k)/2_, the
+    /// vector _(1, 0, 0)_ will be rotated to _(0, 1, 0)_.
+    pub fn rotate(&self, vector: [Real; 3]) -> [Real; 3] {
+        let pure = Self::new(Real::zero(), vector[0], vector[1], vector[2]);
+        (*self * pure * self.inverse_multiplication()).vector()
+    }
+
+    /// Returns the [spherical linear interpolation](https://en.wikipedia.org/wiki/Slerp) between
+    /// the rotations of the normalized `self`, for `t = 0`, and `other`, for `t = 1`, at constant
+    /// angular speed.
+    ///
+    /// As _q_ and _-q_ are the same rotation, it follows the shortest path, which might end at
+    /// _-other_. For almost equal rotations, it falls back to the normalized linear
+    /// interpolation, to avoid dividing by _sin θ ≈ 0_.
+    pub fn slerp(&self, other: &Self, t: Real) -> Self {
+        let start = self.normalized();
+        let mut end = other.normalized();
+        let mut cosine = start.dot(&end);
+        if cosine < Real::zero() {
+            end = -end;
+            cosine = -cosine;
+        }
+        if cosine > Real::new(0.9995) {
+            return (start + (end - start).scaled(t)).normalized();
+        }
+        let angle = cosine.acos();
+        let sine = angle.sin();
+        start.scaled(((Real::one() - t) * angle).sin() / sine)
+            + end.scaled((t * angle).sin() / sine)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for Quaternion {
+    fn from(value: (f32, f32, f32, f32)) -> Self {
+        Self::new(
+            Real::new(value.0),
+            Real::new(value.1),
+            Real::new(value.2),
+            Real::new(value.3),
+        )
+    }
+}
+
+impl From<Real> for Quaternion {
+    fn from(value: Real) -> Self {
+        Self::new(value, Real::zero(), Real::zero(), Real::zero())
+    }
+}
+
+impl std::fmt::Display for Quaternion {
+    /// Writes every component, like `+1+2i+3j-4k`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}i{}j{}k", self.w, self.x, self.y, self.z)
+    }
+}
+
+impl FromStr for Quaternion {
+    type Err = StructureError;
+
+    /// Parses quaternions in the format of [`Display`](std::fmt::Display), like `+1+2i+3j-4k`,
+    /// but also with implicit coefficients, missing components or whitespaces, like `i - 2k`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.split_whitespace().collect::<String>();
+        if input.is_empty() {
+            return Err(StructureError::ParseError("Empty quaternion".to_string()));
+        }
+        let mut terms = Vec::new();
+        let mut start = 0;
+        for (i, character) in input.char_indices() {
+            if matches!(character, '+' | '-') && i > start && !input[..i].ends_with(['e', 'E']) {
+                terms.push(&input[start..i]);
+                start = i;
+            }
+        }
+        terms.push(&input[start..]);
+
+        let mut result = Self::zero();
+        for term in terms {
+            let (coefficient, unit) = match term.strip_suffix(['i', 'j', 'k']) {
+                Some(coefficient) => (coefficient.trim_end_matches('*'), term.chars().last()),
+                None => (term, None),
+            };
+            let coefficient = match coefficient {
+                "" | "+" => Real::one(),
+                "-" => -Real::one(),
+                coefficient => Real::from_str(coefficient)?,
+            };
+            result = result
+                + match unit {
+                    Some('i') => Self::i(),
+                    Some('j') => Self::j(),
+                    Some('k') => Self::k(),
+                    _ => Self::one(),
+                }
+                .scaled(coefficient);
+        }
+        Ok(result)
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Self;
+
+    /// Returns the Hamilton product, which is not commutative.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        )
+    }
+}
+
+impl Zero for Quaternion {
+    fn zero() -> Self {
+        Self::from(Real::zero())
+    }
+
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.equals(&Self::zero(), tolerance)
+    }
+}
+
+impl One for Quaternion {
+    fn one() -> Self {
+        Self::from(Real::one())
+    }
+
+    fn is_one<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.equals(&Self::one(), tolerance)
+    }
+}
+
+impl Equals for Quaternion {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        self.w.equals(&rhs.w, tolerance)
+            && self.x.equals(&rhs.x, tolerance)
+            && self.y.equals(&rhs.y, tolerance)
+            && self.z.equals(&rhs.z, tolerance)
+    }
+}
+
+impl PartialOrd for Quaternion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.norm().partial_cmp(&other.norm())
+    }
+}
+
+impl AsF32 for Quaternion {
+    /// As for [`Complex`](super::complex::Complex), it returns the norm of the `Self`.
+    fn as_f32(&self) -> f32 {
+        self.norm().as_f32()
+    }
+}
+
+impl Abs for Quaternion {
+    type Output = Quaternion;
+
+    fn abs_value(&self) -> Self::Output {
+        Self::from(self.norm())
+    }
+}
+
+impl Group for Quaternion {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        -*self
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+}
+
+impl Ring for Quaternion {
+    fn sum(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{f32::consts::FRAC_PI_2, str::FromStr};
+
+    use super::Quaternion;
+    use crate::{
+        equality::Equals, identities::One, matrix::generic::Matrix, structures::reals::Real,
+    };
+
+    fn vector(x: f32, y: f32, z: f32) -> [Real; 3] {
+        [Real::new(x), Real::new(y), Real::new(z)]
+    }
+
+    #[test]
+    fn hamilton_product() {
+        let (i, j, k) = (Quaternion::i(), Quaternion::j(), Quaternion::k());
+        pretty_assertions::assert_eq!(i * j, k);
+        pretty_assertions::assert_eq!(j * i, -k);
+        pretty_assertions::assert_eq!(j * k, i);
+        pretty_assertions::assert_eq!(k * i, j);
+        for unit in [i, j, k] {
+            pretty_assertions::assert_eq!(unit * unit, -Quaternion::one());
+        }
+        pretty_assertions::assert_eq!(
+            Quaternion::from((1., 2., 3., 4.)) * Quaternion::from((5., 6., 7., 8.)),
+            Quaternion::from((-60., 12., 30., 24.))
+        );
+        pretty_assertions::assert_eq!(i * j * k, -Quaternion::one());
+    }
+
+    #[test]
+    fn conjugate_norm_and_inverse() {
+        let q = Quaternion::from((1., 2., 3., 4.));
+        pretty_assertions::assert_eq!(q.conjugate(), Quaternion::from((1., -2., -3., -4.)));
+        assert!(q.norm().equals(&Real::new(30_f32.sqrt()), 1e-6));
+        assert!((q * q.inverse_multiplication()).is_one(1e-6));
+        assert!((q.inverse_multiplication() * q).is_one(1e-6));
+        let p = Quaternion::from((0., 1., -1., 2.));
+        assert!((p * q).norm().equals(&(p.norm() * q.norm()), 1e-5));
+        assert!(q.normalized().norm().equals(&Real::one(), 1e-6));
+    }
+
+    #[test]
+    fn rotations() {
+        let quarter = Quaternion::from_axis_angle(vector(0., 0., 2.), Real::new(FRAC_PI_2));
+        let rotated = quarter.rotate(vector(1., 0., 0.));
+        for (computed, expected) in rotated.iter().zip(vector(0., 1., 0.).iter()) {
+            assert!(computed.equals(expected, 1e-6));
+        }
+        // The composition of rotations is the product, applying first the right factor.
+        let around_x = Quaternion::from_axis_angle(vector(1., 0., 0.), Real::new(FRAC_PI_2));
+        let composed = (around_x * quarter).rotate(vector(1., 0., 0.));
+        for (computed, expected) in composed.iter().zip(vector(0., 0., 1.).iter()) {
+            assert!(computed.equals(expected, 1e-6));
+        }
+        // The norm of the quaternion does not matter.
+        let scaled = quarter * Quaternion::from((3., 0., 0., 0.));
+        for (computed, expected) in scaled.rotate(vector(1., 0., 0.)).iter().zip(rotated.iter()) {
+            assert!(computed.equals(expected, 1e-5));
+        }
+    }
+
+    #[test]
+    fn spherical_linear_interpolation() {
+        let start = Quaternion::one();
+        let end = Quaternion::from_axis_angle(vector(0., 0., 1.), Real::new(FRAC_PI_2));
+        let half = Quaternion::from_axis_angle(vector(0., 0., 1.), Real::new(FRAC_PI_2 / 2.));
+        assert!(start.slerp(&end, Real::new(0.)).equals(&start, 1e-6));
+        assert!(start.slerp(&end, Real::new(1.)).equals(&end, 1e-6));
+        assert!(start.slerp(&end, Real::new(0.5)).equals(&half, 1e-6));
+        // -end is the same rotation, so the path is the same.
+        assert!(start.slerp(&-end, Real::new(0.5)).equals(&half, 1e-6));
+        assert!(start.slerp(&start, Real::new(0.3)).equals(&start, 1e-6));
+    }
+
+    #[test]
+    fn parse_and_display() {
+        vec![
+            ("+1+2i+3j-4k", (1., 2., 3., -4.)),
+            ("i - 2k", (0., 1., 0., -2.)),
+            ("-j", (0., 0., -1., 0.)),
+            ("2.5", (2.5, 0., 0., 0.)),
+            ("1e-1+3*k", (0.1, 0., 0., 3.)),
+        ]
+        .into_iter()
+        .for_each(|(input, expected)| {
+            let parsed = Quaternion::from_str(input).unwrap();
+            pretty_assertions::assert_eq!(
+                parsed,
+                Quaternion::from(expected),
+                "Test case: {} failed",
+                input
+            );
+            pretty_assertions::assert_eq!(
+                Quaternion::from_str(&parsed.to_string()).unwrap(),
+                parsed
+            );
+        });
+        pretty_assertions::assert_eq!(
+            Quaternion::from((1., 2., 3., -4.)).to_string(),
+            "+1+2i+3j-4k"
+        );
+        assert!(Quaternion::from_str("").is_err());
+        assert!(Quaternion::from_str("1+2l").is_err());
+    }
+
+    #[test]
+    fn matrix_multiplication_keeps_the_order() {
+        let row = Matrix::<Quaternion>::from_str("{{i,j}}").unwrap();
+        let column = Matrix::<Quaternion>::from_str("{{j},{k}}").unwrap();
+        // i·j + j·k = k + i, while j·i + k·j would be -k - i.
+        pretty_assertions::assert_eq!(
+            (row.clone() * column.clone()).unwrap(),
+            Matrix::from_str("{{i+k}}").unwrap()
+        );
+        pretty_assertions::assert_eq!(
+            (column * row).unwrap(),
+            Matrix::from_str("{{-k,-1},{j,-i}}").unwrap()
+        );
+    }
+}
@@ -1,23 +1,26 @@
 use std::{
     fmt::Display,
-    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    ops::{Add, Div, Mul, Neg, Sub},
     str::FromStr,
 };
 
-use super::{errors::StructureError, integers::Integer, Field, Group, Ring};
+use super::{
+    big_integers::BigInt, errors::StructureError, integers::Integer, CommutativeRing,
+    EuclideanDomain, Field, Group, IntegralDomain, Ring,
+};
 
 use crate::{
     arithmetics::euclid,
-    equality::Equals,
+    equality::{Equals, Tolerance},
     identities::{One, Zero},
-    num_types::{AsF32, FromF32},
+    num_types::{AsF32, AsF64, FromF32},
     traits::Abs,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd)]
 pub struct Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     numerator: Integer<R>,
     denominator: Integer<R>,
@@ -33,11 +36,11 @@ macro_rules! impl_rational_from_primitives {
     };
 }
 
-impl_rational_from_primitives!(isize, i8, i16, i32, i64, i128);
+impl_rational_from_primitives!(isize, i8, i16, i32, i64, i128, BigInt);
 
 impl<R> Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     pub fn new(numerator: Integer<R>, denominator: Integer<R>) -> Self {
         Self {
@@ -46,6 +49,18 @@ where
         }
     }
 
+    /// Returns the numerator, which might not be coprime with the denominator. See
+    /// [`Rational::simplified`].
+    pub fn numerator(&self) -> &Integer<R> {
+        &self.numerator
+    }
+
+    /// Returns the denominator, which might not be coprime with the numerator. See
+    /// [`Rational::simplified`].
+    pub fn denominator(&self) -> &Integer<R> {
+        &self.denominator
+    }
+
     pub fn simplified(mut self) -> Self {
         let numerator = self.numerator;
         let denominator = self.denominator;
@@ -58,7 +73,7 @@ where
 
 impl<R> Display for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}/{}", self.numerator, self.denominator)
@@ -67,7 +82,7 @@ where
 
 impl<R> Add for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -82,7 +97,7 @@ where
 
 impl<R> Mul for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -95,24 +110,9 @@ where
     }
 }
 
-impl<R> Rem for Rational<R>
-where
-    R: Ring + PartialOrd,
-{
-    type Output = Self;
-
-    fn rem(self, _: Self) -> Self::Output {
-        Self {
-            numerator: Integer::zero(),
-            denominator: Integer::zero(),
-        }
-        .simplified()
-    }
-}
-
 impl<R> Neg for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -127,7 +127,7 @@ where
 
 impl<R> Sub for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -138,33 +138,33 @@ where
 
 impl<R> Zero for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn zero() -> Self {
         Self::new(Integer::zero(), Integer::one())
     }
 
-    fn is_zero(&self, _: f32) -> bool {
+    fn is_zero<T: Tolerance>(&self, _: T) -> bool {
         self.equals(&Self::zero(), 0.)
     }
 }
 
 impl<R> One for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     fn one() -> Self {
         Self::new(Integer::one(), Integer::one())
     }
 
-    fn is_one(&self, _: f32) -> bool {
+    fn is_one<T: Tolerance>(&self, _: T) -> bool {
         self.equals(&Self::one(), 0.)
     }
 }
 
 impl<R> FromStr for Rational<R>
 where
-    R: Ring + PartialOrd + AsF32 + FromF32,
+    R: EuclideanDomain + PartialOrd + AsF32 + FromF32,
 {
     type Err = StructureError;
 
@@ -198,9 +198,9 @@ where
 
 impl<R> Equals for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
         (self.numerator.clone() * rhs.denominator.clone()).equals(
             &(self.denominator.to_owned() * rhs.numerator.to_owned()),
             tolerance,
@@ -210,16 +210,25 @@ where
 
 impl<R> AsF32 for Rational<R>
 where
-    R: Ring + PartialOrd + AsF32,
+    R: EuclideanDomain + PartialOrd + AsF32,
 {
     fn as_f32(&self) -> f32 {
         self.numerator.as_f32() / self.denominator.as_f32()
     }
 }
 
+impl<R> AsF64 for Rational<R>
+where
+    R: EuclideanDomain + PartialOrd + AsF64,
+{
+    fn as_f64(&self) -> f64 {
+        self.numerator.as_f64() / self.denominator.as_f64()
+    }
+}
+
 impl<R> FromF32 for Rational<R>
 where
-    R: Ring + PartialOrd + FromF32 + AsF32,
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
 {
     /// The implementation of [`FromF32`] for the [`Rational`] type is a bit custom, as it is not trivial
     /// to convert an [`f32`] into a [`Rational`] number. With the tolerance given, this function
@@ -242,7 +251,7 @@ where
 
 impl<R> Abs for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -257,7 +266,7 @@ where
 
 impl<R> Group for Rational<R>
 where
-    R: Ring + PartialOrd + FromF32 + AsF32,
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
 {
     fn identity() -> Self {
         Self::new(Integer::zero(), Integer::one())
@@ -274,7 +283,7 @@ where
 
 impl<R> Div for Rational<R>
 where
-    R: Ring + PartialOrd,
+    R: EuclideanDomain + PartialOrd,
 {
     type Output = Self;
 
@@ -289,7 +298,7 @@ where
 
 impl<R> Ring for Rational<R>
 where
-    R: Ring + PartialOrd + FromF32 + AsF32,
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
 {
     fn sum(&self, rhs: &Self) -> Self {
         self.clone() + rhs.clone()
@@ -298,11 +307,53 @@ where
     fn mul(&self, rhs: &Self) -> Self {
         self.clone() * rhs.clone()
     }
+
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        let numerator = self
+            .numerator
+            .checked_mul(&rhs.denominator)?
+            .checked_add(&rhs.numerator.checked_mul(&self.denominator)?)?;
+        let denominator = self.denominator.checked_mul(&rhs.denominator)?;
+        Some(Self::new(numerator, denominator).simplified())
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        let numerator = self
+            .numerator
+            .checked_mul(&rhs.denominator)?
+            .checked_sub(&rhs.numerator.checked_mul(&self.denominator)?)?;
+        let denominator = self.denominator.checked_mul(&rhs.denominator)?;
+        Some(Self::new(numerator, denominator).simplified())
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        let numerator = self.numerator.checked_mul(&rhs.numerator)?;
+        let denominator = self.denominator.checked_mul(&rhs.denominator)?;
+        Some(Self::new(numerator, denominator).simplified())
+    }
+}
+
+impl<R> CommutativeRing for Rational<R> where R: EuclideanDomain + PartialOrd + FromF32 + AsF32 {}
+
+impl<R> IntegralDomain for Rational<R>
+where
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
+{
+    const IS_FIELD: bool = true;
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.numerator.is_zero(0.) {
+            return None;
+        }
+        let numerator = self.numerator.checked_mul(&rhs.denominator)?;
+        let denominator = self.denominator.checked_mul(&rhs.numerator)?;
+        Some(Self::new(numerator, denominator).simplified())
+    }
 }
 
 impl<R> Field for Rational<R>
 where
-    R: Ring + PartialOrd + FromF32 + AsF32,
+    R: EuclideanDomain + PartialOrd + FromF32 + AsF32,
 {
     fn inverse_multiplication(&self) -> Self {
         Self {
@@ -417,7 +468,7 @@ mod tests {
 
     #[test]
     fn parse_rational_from_string_should_not_fail() {
-        struct TestCase<'a, R: Ring + PartialOrd> {
+        struct TestCase<'a, R: EuclideanDomain + PartialOrd> {
             id: &'a str,
             input: &'a str,
             expected: Rational<R>,
@@ -1,16 +1,16 @@
 use std::{
-    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    ops::{Add, Div, Mul, Neg, Sub},
     str::FromStr,
 };
 
 use crate::{
-    equality::Equals,
+    equality::{Equals, Tolerance},
     identities::{One, Zero},
-    num_types::{AsF32, FromF32},
-    traits::Abs,
+    num_types::{AsF32, AsF64, FromF32, FromF64},
+    traits::{Abs, Sqrt},
 };
 
-use super::{errors::StructureError, Field, Group, Ring};
+use super::{errors::StructureError, CommutativeRing, Field, Group, IntegralDomain, Ring};
 
 #[derive(Clone, Copy, Debug, PartialOrd, PartialEq)]
 pub struct Real {
@@ -33,6 +33,71 @@ impl Real {
     pub fn abs(&self) -> Self {
         Self::new(self.value.abs())
     }
+
+    /// Returns _e<sup>x</sup>_.
+    pub fn exp(&self) -> Self {
+        Self::new(self.value.exp())
+    }
+
+    /// Returns the natural logarithm, which is `NaN` for negative numbers.
+    pub fn ln(&self) -> Self {
+        Self::new(self.value.ln())
+    }
+
+    /// Returns _x<sup>n</sup>_ for an integer exponent.
+    pub fn powi(&self, n: i32) -> Self {
+        Self::new(self.value.powi(n))
+    }
+
+    /// Returns _x<sup>y</sup>_, which is `NaN` for negative bases and non integer exponents.
+    pub fn powf(&self, exponent: Real) -> Self {
+        Self::new(self.value.powf(exponent.value))
+    }
+
+    pub fn sin(&self) -> Self {
+        Self::new(self.value.sin())
+    }
+
+    pub fn cos(&self) -> Self {
+        Self::new(self.value.cos())
+    }
+
+    pub fn tan(&self) -> Self {
+        Self::new(self.value.tan())
+    }
+
+    /// Returns the arcsine, in _[-π/2, π/2]_.
+    pub fn asin(&self) -> Self {
+        Self::new(self.value.asin())
+    }
+
+    /// Returns the arccosine, in _[0, π]_.
+    pub fn acos(&self) -> Self {
+        Self::new(self.value.acos())
+    }
+
+    /// Returns the arctangent, in _[-π/2, π/2]_.
+    pub fn atan(&self) -> Self {
+        Self::new(self.value.atan())
+    }
+
+    /// Returns the angle of the point _(x, y)_ with the positive _x_ axis, in _(-π, π]_, where
+    /// `self` is _y_.
+    pub fn atan2(&self, x: Real) -> Self {
+        Self::new(self.value.atan2(x.value))
+    }
+
+    pub fn sinh(&self) -> Self {
+        Self::new(self.value.sinh())
+    }
+
+    pub fn cosh(&self) -> Self {
+        Self::new(self.value.cosh())
+    }
+
+    pub fn tanh(&self) -> Self {
+        Self::new(self.value.tanh())
+    }
 }
 
 impl Add for Real {
@@ -50,7 +115,7 @@ impl Zero for Real {
         Self::new(0_f32)
     }
 
-    fn is_zero(&self, tolerance: f32) -> bool {
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
         self.equals(&Self::zero(), tolerance)
     }
 }
@@ -86,8 +151,8 @@ impl Sub for Real {
 }
 
 impl Equals for Real {
-    fn equals(&self, rhs: &Self, tolerance: f32) -> bool {
-        (self.value - rhs.value).abs() < tolerance
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        self.value.equals(&rhs.value, tolerance)
     }
 }
 
@@ -103,6 +168,12 @@ impl AsF32 for Real {
     }
 }
 
+impl AsF64 for Real {
+    fn as_f64(&self) -> f64 {
+        self.value as f64
+    }
+}
+
 impl Abs for Real {
     type Output = Real;
     fn abs_value(&self) -> Self::Output {
@@ -110,6 +181,12 @@ impl Abs for Real {
     }
 }
 
+impl Sqrt for Real {
+    fn sqrt_value(&self) -> Self {
+        self.sqrt()
+    }
+}
+
 impl Group for Real {
     fn identity() -> Self {
         Self::zero()
@@ -137,20 +214,203 @@ impl One for Real {
         Self::new(1_f32)
     }
 
-    fn is_one(&self, tolerance: f32) -> bool {
+    fn is_one<T: Tolerance>(&self, tolerance: T) -> bool {
         self.value.equals(&1_f32, tolerance)
     }
 }
 
-impl Rem for Real {
+impl Div for Real {
     type Output = Self;
 
-    fn rem(self, rhs: Self) -> Self::Output {
-        Self::new(self.value % rhs.value)
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(self.value / rhs.value)
     }
 }
 
-impl Div for Real {
+impl Ring for Real {
+    fn sum(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        *self * *rhs
+    }
+}
+
+impl CommutativeRing for Real {}
+
+impl IntegralDomain for Real {
+    const IS_FIELD: bool = true;
+}
+
+impl Field for Real {
+    fn inverse_multiplication(&self) -> Self {
+        Self::new(1_f32 / self.value)
+    }
+}
+
+/// Double precision counterpart of [`Real`], which wraps an [`f64`].
+///
+/// Its comparisons are made in [`f64`], whatever the [`Tolerance`], so a tolerance like `1e-12`
+/// is meaningful, while for [`Real`] it is below the precision of the values.
+///
+/// The generic algorithms, as the [QR decomposition](crate::matrix::generic::Matrix::qr), accept
+/// it, but the singular value and the symmetric eigenvalue decompositions are only implemented
+/// for [`Real`].
+#[derive(Clone, Copy, Debug, PartialOrd, PartialEq)]
+pub struct Real64 {
+    value: f64,
+}
+
+impl Real64 {
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn sqrt(&self) -> Self {
+        Self::new(self.value.sqrt())
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(self.value.abs())
+    }
+}
+
+impl From<Real> for Real64 {
+    fn from(value: Real) -> Self {
+        Self::new(value.as_f64())
+    }
+}
+
+impl Add for Real64 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value)
+    }
+}
+
+impl Zero for Real64 {
+    fn zero() -> Self {
+        Self::new(0_f64)
+    }
+
+    fn is_zero<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.equals(&Self::zero(), tolerance)
+    }
+}
+
+impl FromStr for Real64 {
+    type Err = StructureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(f64::from_str(s)?))
+    }
+}
+
+impl std::fmt::Display for Real64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+}", self.value)
+    }
+}
+
+impl Neg for Real64 {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value)
+    }
+}
+
+impl Sub for Real64 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value)
+    }
+}
+
+impl Equals for Real64 {
+    fn equals<T: Tolerance>(&self, rhs: &Self, tolerance: T) -> bool {
+        self.value.equals(&rhs.value, tolerance)
+    }
+}
+
+impl FromF32 for Real64 {
+    fn from_f32(value: f32, _: f32) -> Self {
+        Self::new(value as f64)
+    }
+}
+
+impl FromF64 for Real64 {
+    fn from_f64(value: f64, _: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl AsF32 for Real64 {
+    /// Rounds the value to the closest [`f32`], so precision is lost.
+    fn as_f32(&self) -> f32 {
+        self.value as f32
+    }
+}
+
+impl AsF64 for Real64 {
+    fn as_f64(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Abs for Real64 {
+    type Output = Real64;
+    fn abs_value(&self) -> Self::Output {
+        Self::new(self.value.abs())
+    }
+}
+
+impl Sqrt for Real64 {
+    fn sqrt_value(&self) -> Self {
+        self.sqrt()
+    }
+}
+
+impl Group for Real64 {
+    fn identity() -> Self {
+        Self::zero()
+    }
+
+    fn inverse(&self) -> Self {
+        Self::new(-self.value)
+    }
+
+    fn op(&self, rhs: &Self) -> Self {
+        *self + *rhs
+    }
+}
+
+impl Mul for Real64 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.value * rhs.value)
+    }
+}
+
+impl One for Real64 {
+    fn one() -> Self {
+        Self::new(1_f64)
+    }
+
+    fn is_one<T: Tolerance>(&self, tolerance: T) -> bool {
+        self.equals(&Self::one(), tolerance)
+    }
+}
+
+impl Div for Real64 {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
@@ -158,7 +418,7 @@ impl Div for Real {
     }
 }
 
-impl Ring for Real {
+impl Ring for Real64 {
     fn sum(&self, rhs: &Self) -> Self {
         *self + *rhs
     }
@@ -168,17 +428,34 @@ impl Ring for Real {
     }
 }
 
-impl Field for Real {
+impl CommutativeRing for Real64 {}
+
+impl IntegralDomain for Real64 {
+    const IS_FIELD: bool = true;
+}
+
+impl Field for Real64 {
     fn inverse_multiplication(&self) -> Self {
-        Self::new(1_f32 / self.value)
+        Self::new(1_f64 / self.value)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::equality::Equals;
+    use std::str::FromStr;
+
+    use crate::{
+        equality::Equals,
+        identities::One,
+        matrix::{
+            generic::{qr::QrMethod, Matrix},
+            square::SquareMatrix,
+            AsMatrix,
+        },
+        structures::Field,
+    };
 
-    use super::Real;
+    use super::{Real, Real64};
 
     #[test]
     fn equals_with_tolerance() {
@@ -213,4 +490,67 @@ mod test {
             .into_iter()
             .for_each(|tolerance| assert!(div.equals(&Real::new(1.0003701), tolerance)));
     }
+
+    #[test]
+    fn transcendental_functions() {
+        let x = Real::new(0.5);
+        assert!(x.exp().ln().equals(&x, 1e-6));
+        assert!((x.sin() * x.sin() + x.cos() * x.cos()).equals(&Real::new(1.), 1e-6));
+        assert!(x.tan().atan().equals(&x, 1e-6));
+        assert!(x.sin().asin().equals(&x, 1e-6));
+        assert!(x.cos().acos().equals(&x, 1e-6));
+        assert!((x.cosh() * x.cosh() - x.sinh() * x.sinh()).equals(&Real::new(1.), 1e-5));
+        assert!(x.tanh().equals(&(x.sinh() / x.cosh()), 1e-6));
+        assert!(Real::new(2.).powi(-2).equals(&Real::new(0.25), 1e-6));
+        assert!(Real::new(8.)
+            .powf(Real::new(1. / 3.))
+            .equals(&Real::new(2.), 1e-6));
+        assert!(Real::new(-1.)
+            .atan2(Real::new(-1.))
+            .equals(&Real::new(-3. * std::f32::consts::FRAC_PI_4), 1e-6));
+        assert!(Real::new(-1.).ln().value().is_nan());
+    }
+
+    #[test]
+    fn real64_tolerances_below_f32_precision() {
+        let x = Real64::new(1.234_567_890_123);
+        let y = Real64::new(1.234_567_890_124);
+        assert!(x.equals(&y, 1e-11));
+        assert!(!x.equals(&y, 1e-13));
+        assert!(x.equals(&y, 1e-11_f64) && !x.equals(&y, 1e-13_f64));
+        // The same values collapse to a single f32.
+        assert!(Real::new(x.value() as f32).equals(&Real::new(y.value() as f32), f32::MIN_POSITIVE));
+
+        let third = Real64::new(3.).inverse_multiplication();
+        assert!((third * Real64::new(3.)).is_one(1e-15));
+        assert!((third + third + third - Real64::new(1.)).abs().value() < 1e-15);
+    }
+
+    #[test]
+    fn real64_matrices() {
+        let matrix = SquareMatrix::<Real64>::from_str("{{4,3},{6,3}}").unwrap();
+        let inverse = matrix.inverse_gauss_jordan(1e-12).unwrap();
+        assert!(inverse.equals(
+            &SquareMatrix::new(
+                2,
+                vec![
+                    vec![Real64::new(-0.5), Real64::new(0.5)],
+                    vec![Real64::new(1.), Real64::new(-2. / 3.)],
+                ]
+            ),
+            1e-12
+        ));
+
+        let tall =
+            Matrix::<Real64>::from_str("{{12,-51,4},{6,167,-68},{-4,24,-41},{1,1,1}}").unwrap();
+        let qr = tall.qr(QrMethod::Householder, 1e-12).unwrap();
+        assert!((qr.q().clone() * qr.r().clone())
+            .unwrap()
+            .equals(&tall, 1e-12_f64));
+
+        // Below the range of f32, the tolerances keep their double precision.
+        let tiny = Matrix::<Real64>::from_str("{{1,0},{0,1e-60}}").unwrap();
+        pretty_assertions::assert_eq!(tiny.rank(1e-50_f64), Ok(1));
+        pretty_assertions::assert_eq!(tiny.rank(1e-70_f64), Ok(2));
+    }
 }
@@ -15,3 +15,21 @@ macro_rules! impl_abs {
 }
 
 impl_abs!(i8 i16 i32 i64 i128 isize f32 f64);
+
+/// Trait for the values that have a square root, as the [`Real`](crate::structures::reals::Real)
+/// numbers. It is needed by the orthogonal factorizations, which normalize vectors.
+pub trait Sqrt {
+    fn sqrt_value(&self) -> Self;
+}
+
+macro_rules! impl_sqrt {
+    ($($t:ty)*) => ($(
+        impl Sqrt for $t {
+            fn sqrt_value(&self) -> Self {
+                self.sqrt()
+            }
+        }
+    )*)
+}
+
+impl_sqrt!(f32 f64);